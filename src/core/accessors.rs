@@ -0,0 +1,187 @@
+use crate::core::Matrix;
+use std::ops::Range;
+
+impl Matrix {
+    /// Returns the element at the given position, or `None` if out of bounds
+    ///
+    /// # Arguments
+    /// * `row` - Row index (0-based)
+    /// * `col` - Column index (0-based)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[1.0, 2.0]);
+    /// assert_eq!(m.get(1, 1), Some(2.0));
+    /// assert_eq!(m.get(2, 0), None);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if row >= self.rows || col >= self.columns {
+            return None;
+        }
+        self.data.get(row * self.columns + col).copied()
+    }
+
+    /// Returns a mutable reference to the element at the given position,
+    /// or `None` if out of bounds
+    ///
+    /// # Arguments
+    /// * `row` - Row index (0-based)
+    /// * `col` - Column index (0-based)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let mut m = Matrix::zeros(2, 2);
+    /// if let Some(x) = m.get_mut(0, 1) {
+    ///     *x = 5.0;
+    /// }
+    /// assert_eq!(m.data, vec![0.0, 5.0, 0.0, 0.0]);
+    /// assert!(m.get_mut(0, 2).is_none());
+    /// ```
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut f64> {
+        if row >= self.rows || col >= self.columns {
+            return None;
+        }
+        self.data.get_mut(row * self.columns + col)
+    }
+
+    /// Returns the matrix elements as a row-major slice
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[1.0, 2.0]);
+    /// assert_eq!(m.as_slice(), &[1.0, 0.0, 0.0, 2.0]);
+    /// ```
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns the matrix elements as a mutable row-major slice
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let mut m = Matrix::zeros(2, 2);
+    /// m.as_mut_slice()[3] = 1.0;
+    /// assert_eq!(m[1][1], 1.0);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.data
+    }
+
+    /// Iterates mutably over all elements together with their `(row, column)` position
+    ///
+    /// Elements are visited in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let mut m = Matrix::ones(2, 2);
+    /// for (row, col, x) in m.iter_mut_indexed() {
+    ///     *x = (row * 10 + col) as f64;
+    /// }
+    /// assert_eq!(m.data, vec![0.0, 1.0, 10.0, 11.0]);
+    /// ```
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = (usize, usize, &mut f64)> {
+        let columns = self.columns;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, x)| (index / columns, index % columns, x))
+    }
+
+    /// Returns the elements of the `k`-th diagonal
+    ///
+    /// `k = 0` is the main diagonal, positive `k` the superdiagonals (element
+    /// `(i, i + k)`) and negative `k` the subdiagonals (element `(i - k, i)`).
+    /// Non-square matrices are supported.
+    ///
+    /// # Arguments
+    /// * `k` - Diagonal offset
+    ///
+    /// # Returns
+    /// Diagonal elements from top-left to bottom-right; empty if `k` lies outside the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.kth_diagonal(0), vec![1.0, 4.0]);
+    /// assert_eq!(m.kth_diagonal(-1), vec![3.0]);
+    /// assert!(m.kth_diagonal(2).is_empty());
+    /// ```
+    pub fn kth_diagonal(&self, k: i64) -> Vec<f64> {
+        let offset = k.unsigned_abs() as usize;
+        let (first_row, first_col) = if k >= 0 { (0, offset) } else { (offset, 0) };
+        if first_row >= self.rows || first_col >= self.columns {
+            return Vec::new();
+        }
+
+        let length = (self.rows - first_row).min(self.columns - first_col);
+        (0..length)
+            .map(|i| self.data[(first_row + i) * self.columns + first_col + i])
+            .collect()
+    }
+
+    /// Removes a row in place and returns its elements
+    ///
+    /// # Arguments
+    /// * `i` - Index of the row to remove
+    ///
+    /// # Returns
+    /// - `Ok(Vec<f64>)` containing the removed row; the matrix has one row fewer
+    /// - `Err` if `i` is out of bounds or the row is the matrix's last one
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let mut m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.take_row(0).unwrap(), vec![1.0, 2.0]);
+    /// assert_eq!((m.rows, m.data), (1, vec![3.0, 4.0]));
+    /// ```
+    pub fn take_row(&mut self, i: usize) -> Result<Vec<f64>, String> {
+        if i >= self.rows {
+            return Err(format!(
+                "Row index {} out of bounds for matrix with {} rows",
+                i, self.rows
+            ));
+        }
+        if self.rows == 1 {
+            return Err("Cannot take the only remaining row of a matrix".to_string());
+        }
+
+        let row: Vec<f64> = self.data.drain(i * self.columns..(i + 1) * self.columns).collect();
+        self.rows -= 1;
+        Ok(row)
+    }
+
+    /// Copies out the contiguous block at the given row and column ranges
+    ///
+    /// # Arguments
+    /// * `rows` - Row indices to copy (end exclusive)
+    /// * `cols` - Column indices to copy (end exclusive)
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of size `rows.len() x cols.len()`
+    /// - `Err` if a range is reversed or extends past the matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: (1..=9).map(f64::from).collect(), rows: 3, columns: 3 };
+    /// assert_eq!(m.submatrix(0..2, 1..3).unwrap().data, vec![2.0, 3.0, 5.0, 6.0]);
+    /// assert!(m.submatrix(2..4, 0..1).is_err());
+    /// ```
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Self, String> {
+        if rows.start > rows.end || rows.end > self.rows || cols.start > cols.end || cols.end > self.columns {
+            return Err(format!(
+                "Block {:?} x {:?} out of bounds for {}x{} matrix",
+                rows, cols, self.rows, self.columns
+            ));
+        }
+
+        Ok(self.block(rows, cols))
+    }
+}
@@ -0,0 +1,138 @@
+use crate::core::Matrix;
+
+/// Streaming per-component mean and variance of fixed-length vectors.
+///
+/// Uses Welford's online algorithm, so samples can be pushed one at a time
+/// without being stored and without the cancellation of the naive
+/// sum-of-squares formula. Results are returned as column vectors.
+///
+/// # Fields
+/// - `count`: Number of vectors pushed so far
+/// - `mean`: Running mean of each component
+/// - `m2`: Running sum of squared deviations from the mean of each component
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorAccumulator {
+    count: usize,
+    mean: Vec<f64>,
+    m2: Vec<f64>,
+}
+
+impl VectorAccumulator {
+    /// Creates an empty accumulator for vectors of the given length
+    ///
+    /// # Arguments
+    /// * `dimension` - Length of the vectors that will be pushed
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::VectorAccumulator;
+    /// let acc = VectorAccumulator::new(3);
+    /// assert_eq!(acc.count(), 0);
+    /// ```
+    pub fn new(dimension: usize) -> Self {
+        VectorAccumulator {
+            count: 0,
+            mean: vec![0.0; dimension],
+            m2: vec![0.0; dimension],
+        }
+    }
+
+    /// Adds a sample vector
+    ///
+    /// # Arguments
+    /// * `v` - Vector (1xN or Nx1) with the accumulator's length
+    ///
+    /// # Returns
+    /// - `Ok(())` if the sample was accumulated
+    /// - `Err` if `v` isn't a vector or its length differs
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, VectorAccumulator};
+    /// let mut acc = VectorAccumulator::new(2);
+    /// acc.push(&Matrix { data: vec![1.0, 2.0], rows: 1, columns: 2 }).unwrap();
+    /// assert!(acc.push(&Matrix::ones(3, 1)).is_err());
+    /// ```
+    pub fn push(&mut self, v: &Matrix) -> Result<(), String> {
+        if !v.check_vector() || v.data.len() != self.mean.len() {
+            return Err(format!(
+                "Expected a vector of length {}, got a {}x{} matrix",
+                self.mean.len(), v.rows, v.columns
+            ));
+        }
+
+        self.count += 1;
+        let n = self.count as f64;
+        for (i, &x) in v.data.iter().enumerate() {
+            let delta = x - self.mean[i];
+            self.mean[i] += delta / n;
+            self.m2[i] += delta * (x - self.mean[i]);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of vectors pushed so far
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, VectorAccumulator};
+    /// let mut acc = VectorAccumulator::new(1);
+    /// acc.push(&Matrix::ones(1, 1)).unwrap();
+    /// assert_eq!(acc.count(), 1);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the per-component mean as a column vector
+    ///
+    /// # Returns
+    /// Column vector of means (zeros before any sample is pushed)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, VectorAccumulator};
+    /// let mut acc = VectorAccumulator::new(2);
+    /// acc.push(&Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 }).unwrap();
+    /// acc.push(&Matrix { data: vec![3.0, 6.0], rows: 2, columns: 1 }).unwrap();
+    /// assert_eq!(acc.mean().data, vec![2.0, 4.0]);
+    /// ```
+    pub fn mean(&self) -> Matrix {
+        Matrix {
+            data: self.mean.clone(),
+            rows: self.mean.len(),
+            columns: 1,
+        }
+    }
+
+    /// Returns the per-component population variance as a column vector
+    ///
+    /// Divides the sum of squared deviations by the number of samples `n`;
+    /// multiply by `n / (n - 1)` for the unbiased sample variance.
+    ///
+    /// # Returns
+    /// Column vector of variances (zeros before any sample is pushed)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, VectorAccumulator};
+    /// let mut acc = VectorAccumulator::new(2);
+    /// acc.push(&Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 }).unwrap();
+    /// acc.push(&Matrix { data: vec![3.0, 6.0], rows: 2, columns: 1 }).unwrap();
+    /// assert_eq!(acc.variance().data, vec![1.0, 4.0]);
+    /// ```
+    pub fn variance(&self) -> Matrix {
+        let data = if self.count == 0 {
+            vec![0.0; self.m2.len()]
+        } else {
+            self.m2.iter().map(|&m2| m2 / self.count as f64).collect()
+        };
+
+        Matrix {
+            rows: data.len(),
+            data,
+            columns: 1,
+        }
+    }
+}
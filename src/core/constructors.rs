@@ -17,9 +17,9 @@ impl Matrix {
     /// //  [0.9012, 0.3456]]
     /// ```
     pub fn new(rows: usize, columns: usize) -> Self {
-        let mut rng = rand::thread_rng();
+        let mut rng = rand::rng();
         Matrix {
-            data: (0..rows * columns).map(|_| rng.gen_range(0.0..1.0)).collect(),
+            data: (0..rows * columns).map(|_| rng.random_range(0.0..1.0)).collect(),
             rows,
             columns,
         }
@@ -90,6 +90,35 @@ impl Matrix {
         }
     }
 
+    /// Creates the standard basis vector `e_index` as a column vector
+    ///
+    /// # Arguments
+    /// * `size` - Length of the vector
+    /// * `index` - Position of the single `1.0` entry
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of shape `size x 1` with `1.0` at `index` and zeros elsewhere
+    /// - `Err` if `index >= size`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let e1 = Matrix::basis_vector(3, 1).unwrap();
+    /// assert_eq!(e1.data, vec![0.0, 1.0, 0.0]);
+    /// ```
+    pub fn basis_vector(size: usize, index: usize) -> Result<Self, String> {
+        if index >= size {
+            return Err(format!(
+                "Basis index {} out of range for vector of length {}",
+                index, size
+            ));
+        }
+
+        let mut vector = Matrix::zeros(size, 1);
+        vector.data[index] = 1.0;
+        Ok(vector)
+    }
+
     /// Creates a diagonal matrix from a vector of values
     ///
     /// # Arguments
@@ -119,4 +148,323 @@ impl Matrix {
             columns: size,
         }
     }
-}
\ No newline at end of file
+
+    /// Creates a matrix by copying row-major data from a slice
+    ///
+    /// # Arguments
+    /// * `rows` - Number of rows
+    /// * `columns` - Number of columns
+    /// * `slice` - Row-major elements, exactly `rows * columns` long
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` if the slice length matches the dimensions
+    /// - `Err` if the slice length doesn't match
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::from_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]).unwrap();
+    /// assert_eq!(m[1], [3.0, 4.0]);
+    /// assert!(Matrix::from_slice(2, 2, &[1.0, 2.0, 3.0]).is_err());
+    /// ```
+    pub fn from_slice(rows: usize, columns: usize, slice: &[f64]) -> Result<Self, String> {
+        if slice.len() != rows * columns {
+            return Err(format!(
+                "Expected {} elements for {}x{} matrix, got {}",
+                rows * columns, rows, columns, slice.len()
+            ));
+        }
+        Ok(Matrix {
+            data: slice.to_vec(),
+            rows,
+            columns,
+        })
+    }
+
+    /// Creates a matrix from a list of rows, inferring its dimensions
+    ///
+    /// # Arguments
+    /// * `rows` - Rows of the matrix, all of the same length
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` with `rows.len()` rows
+    /// - `Err` if `rows` is empty or the rows have differing lengths
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// assert_eq!(m.data, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert!(Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0]]).is_err());
+    /// ```
+    pub fn from_rows(rows: &[Vec<f64>]) -> Result<Self, String> {
+        let columns = match rows.first() {
+            Some(first) => first.len(),
+            None => return Err("Cannot build a matrix from zero rows".to_string()),
+        };
+
+        if let Some((index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != columns) {
+            return Err(format!(
+                "Row {} has {} elements, expected {} like the first row",
+                index, row.len(), columns
+            ));
+        }
+
+        Ok(Matrix {
+            data: rows.concat(),
+            rows: rows.len(),
+            columns,
+        })
+    }
+
+    /// Creates a symmetric matrix from its packed upper triangle
+    ///
+    /// # Arguments
+    /// * `values` - The `n*(n+1)/2` entries on and above the diagonal, in row-major
+    ///   order (`a00, a01, ..., a0(n-1), a11, ..., a(n-1)(n-1)`)
+    /// * `n` - Size of the matrix
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the `n x n` symmetric matrix
+    /// - `Err` if `values` doesn't have exactly `n*(n+1)/2` elements
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::from_upper_triangle(&[1.0, 2.0, 3.0], 2).unwrap();
+    /// assert_eq!(m.data, vec![1.0, 2.0, 2.0, 3.0]);
+    /// ```
+    pub fn from_upper_triangle(values: &[f64], n: usize) -> Result<Self, String> {
+        let expected = n * (n + 1) / 2;
+        if values.len() != expected {
+            return Err(format!(
+                "Expected {} upper-triangular elements for {}x{} matrix, got {}",
+                expected, n, n, values.len()
+            ));
+        }
+
+        let mut data = vec![0.0; n * n];
+        let mut packed = values.iter();
+        for row in 0..n {
+            for col in row..n {
+                let value = *packed.next().unwrap();
+                data[row * n + col] = value;
+                data[col * n + row] = value;
+            }
+        }
+
+        Ok(Matrix {
+            data,
+            rows: n,
+            columns: n,
+        })
+    }
+
+    /// Samples a function of two variables on a regular grid
+    ///
+    /// Row `i` corresponds to `y_i = y_min + i * (y_max - y_min) / (rows - 1)` and
+    /// column `j` to `x_j = x_min + j * (x_max - x_min) / (cols - 1)`, so both end
+    /// points are included and element `(i, j)` holds `f(x_j, y_i)`. A single row
+    /// (or column) samples only `y_min` (or `x_min`).
+    ///
+    /// # Arguments
+    /// * `x_range` - `(x_min, x_max)` spanned by the columns
+    /// * `y_range` - `(y_min, y_max)` spanned by the rows
+    /// * `rows` - Number of samples along y
+    /// * `cols` - Number of samples along x
+    /// * `f` - Function evaluated as `f(x, y)`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::sample_grid((0.0, 1.0), (0.0, 2.0), 3, 2, |x, y| x + y);
+    /// assert_eq!(m.data, vec![0.0, 1.0,
+    ///                         1.0, 2.0,
+    ///                         2.0, 3.0]);
+    /// ```
+    pub fn sample_grid<F>(x_range: (f64, f64), y_range: (f64, f64), rows: usize, cols: usize, f: F) -> Self
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        let coordinate = |(start, end): (f64, f64), count: usize, index: usize| {
+            if count > 1 {
+                start + index as f64 * (end - start) / (count - 1) as f64
+            } else {
+                start
+            }
+        };
+
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            let y = coordinate(y_range, rows, i);
+            for j in 0..cols {
+                data.push(f(coordinate(x_range, cols, j), y));
+            }
+        }
+
+        Matrix {
+            data,
+            rows,
+            columns: cols,
+        }
+    }
+
+    /// Creates a matrix whose columns are the given vectors
+    ///
+    /// # Arguments
+    /// * `vectors` - Vectors (1xN or Nx1) of equal length, one per column
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of shape `N x vectors.len()` (an empty matrix for an empty slice)
+    /// - `Err` if any input isn't a vector or the lengths differ
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 };
+    /// let b = Matrix { data: vec![3.0, 4.0], rows: 2, columns: 1 };
+    /// let m = Matrix::from_column_vectors(&[a, b]).unwrap();
+    /// assert_eq!(m.data, vec![1.0, 3.0, 2.0, 4.0]);
+    /// ```
+    pub fn from_column_vectors(vectors: &[Matrix]) -> Result<Self, String> {
+        let Some(first) = vectors.first() else {
+            return Ok(Matrix {
+                data: vec![],
+                rows: 0,
+                columns: 0,
+            });
+        };
+
+        let rows = first.data.len();
+        for (index, vector) in vectors.iter().enumerate() {
+            if !vector.check_vector() {
+                return Err(format!(
+                    "Element {} is a {}x{} matrix, expected a vector (1xN or Nx1)",
+                    index, vector.rows, vector.columns
+                ));
+            }
+            if vector.data.len() != rows {
+                return Err(format!(
+                    "Vector {} has length {}, expected {}",
+                    index, vector.data.len(), rows
+                ));
+            }
+        }
+
+        let columns = vectors.len();
+        let mut data = vec![0.0; rows * columns];
+        for (col, vector) in vectors.iter().enumerate() {
+            for (row, &value) in vector.data.iter().enumerate() {
+                data[row * columns + col] = value;
+            }
+        }
+
+        Ok(Matrix {
+            data,
+            rows,
+            columns,
+        })
+    }
+
+    /// Creates the skew-symmetric cross-product matrix `[v]×` of a 3D vector
+    ///
+    /// The result satisfies `[v]× * w == v × w` for any 3D column vector `w`.
+    ///
+    /// # Arguments
+    /// * `v` - 3D vector (3x1 or 1x3)
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the 3x3 skew-symmetric matrix
+    /// - `Err` if `v` isn't a 3D vector
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let v = Matrix { data: vec![1.0, 2.0, 3.0], rows: 3, columns: 1 };
+    /// let skew = Matrix::skew_symmetric(&v).unwrap();
+    /// assert_eq!(skew.data, vec![ 0.0, -3.0,  2.0,
+    ///                             3.0,  0.0, -1.0,
+    ///                            -2.0,  1.0,  0.0]);
+    /// ```
+    pub fn skew_symmetric(v: &Matrix) -> Result<Self, String> {
+        if !v.check_3d_vector() {
+            return Err("Skew-symmetric matrix only defined for 3D vectors (length 3)".to_string());
+        }
+
+        let (x, y, z) = (v.data[0], v.data[1], v.data[2]);
+
+        Ok(Matrix {
+            data: vec![
+                0.0, -z, y,
+                z, 0.0, -x,
+                -y, x, 0.0,
+            ],
+            rows: 3,
+            columns: 3,
+        })
+    }
+
+    /// Creates a matrix by stacking a row vector `times` times
+    ///
+    /// # Arguments
+    /// * `row` - Row vector (1xM)
+    /// * `times` - Number of rows in the result
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of shape `times x M` with every row equal to `row`
+    /// - `Err` if `row` isn't a 1xM matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let row = Matrix { data: vec![1.0, 2.0], rows: 1, columns: 2 };
+    /// let m = Matrix::repeat_row(&row, 3).unwrap();
+    /// assert_eq!(m.data, vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+    /// ```
+    pub fn repeat_row(row: &Matrix, times: usize) -> Result<Self, String> {
+        if row.rows != 1 {
+            return Err(format!(
+                "Expected a row vector (1xM), got a {}x{} matrix",
+                row.rows, row.columns
+            ));
+        }
+
+        Ok(Matrix {
+            data: row.data.repeat(times),
+            rows: times,
+            columns: row.columns,
+        })
+    }
+
+    /// Creates a matrix by placing a column vector side by side `times` times
+    ///
+    /// # Arguments
+    /// * `column` - Column vector (Nx1)
+    /// * `times` - Number of columns in the result
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of shape `N x times` with every column equal to `column`
+    /// - `Err` if `column` isn't an Nx1 matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let column = Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 };
+    /// let m = Matrix::repeat_column(&column, 3).unwrap();
+    /// assert_eq!(m.data, vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    /// ```
+    pub fn repeat_column(column: &Matrix, times: usize) -> Result<Self, String> {
+        if column.columns != 1 {
+            return Err(format!(
+                "Expected a column vector (Nx1), got a {}x{} matrix",
+                column.rows, column.columns
+            ));
+        }
+
+        Ok(Matrix {
+            data: column.data.iter().flat_map(|&x| std::iter::repeat_n(x, times)).collect(),
+            rows: column.rows,
+            columns: times,
+        })
+    }
+}
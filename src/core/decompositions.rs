@@ -0,0 +1,889 @@
+use crate::core::Matrix;
+
+/// Upper bound on cyclic Jacobi sweeps; convergence is quadratic so this is never reached in practice
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Upper bound on QR iterations spent deflating a single eigenvalue
+const MAX_QR_ITERATIONS: usize = 500;
+
+/// Absolute tolerance on `|a_ij - a_ji|` accepted by `eigenvalues_symmetric`
+const EIGEN_SYMMETRY_TOLERANCE: f64 = 1e-9;
+
+/// Relative tolerance for treating a matrix as symmetric in `diagonalize`
+const SYMMETRY_TOLERANCE: f64 = 1e-12;
+
+/// Relative tolerance for grouping eigenvalues and detecting null-space vectors in `diagonalize`
+const EIGENVALUE_CLUSTER_TOLERANCE: f64 = 1e-6;
+
+impl Matrix {
+    /// Computes the LU decomposition with partial pivoting, `P A = L U`
+    ///
+    /// At each step the row with the largest entry in the current column is
+    /// swapped into the pivot position. A column whose remaining entries are all
+    /// zero is left unreduced (zero multipliers in `L`) instead of being divided by,
+    /// so singular matrices factor without `NaN`.
+    ///
+    /// # Returns
+    /// - `Ok((L, U, permutation))` where `L` is unit lower triangular, `U` is upper
+    ///   triangular and `permutation[i]` is the original row moved to row `i`, i.e.
+    ///   row `i` of `L * U` equals row `permutation[i]` of `self`
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// let (l, u, permutation) = m.lu_decomposition().unwrap();
+    /// assert_eq!(permutation, vec![1, 0]);
+    /// assert_eq!(l.data, vec![1.0, 0.0, 1.0 / 3.0, 1.0]);
+    /// assert_eq!(u.data[..3], [3.0, 4.0, 0.0]);
+    /// ```
+    pub fn lu_decomposition(&self) -> Result<(Matrix, Matrix, Vec<usize>), String> {
+        let factors = self.lu_factorize()?;
+
+        let n = self.rows;
+        let mut l = Matrix::identity(n);
+        let mut u = Matrix::zeros(n, n);
+        for row in 0..n {
+            for col in 0..n {
+                let value = factors.lu.data[row * n + col];
+                if col < row {
+                    l.data[row * n + col] = value;
+                } else {
+                    u.data[row * n + col] = value;
+                }
+            }
+        }
+
+        Ok((l, u, factors.permutation))
+    }
+
+    /// Solves the linear system `A X = B`
+    ///
+    /// Factors `A` once with partial-pivoting LU and then solves each column of `B`
+    /// by forward and back substitution, which is cheaper and more accurate than
+    /// multiplying by the inverse.
+    ///
+    /// # Arguments
+    /// * `b` - Right-hand side with `rows` equal to the size of `A`; each column is
+    ///   solved independently
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing `X`, with the same shape as `b`
+    /// - `Err` if `A` isn't square, `b` has the wrong number of rows, or `A` is
+    ///   singular (a pivot negligible relative to its original row and column)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix { data: vec![2.0, 1.0, 1.0, 3.0], rows: 2, columns: 2 };
+    /// let b = Matrix { data: vec![3.0, 5.0], rows: 2, columns: 1 };
+    /// let x = a.solve(&b).unwrap();
+    /// assert!((x.data[0] - 0.8).abs() < 1e-12 && (x.data[1] - 1.4).abs() < 1e-12);
+    /// ```
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, String> {
+        let factors = self.lu_factorize()?;
+        b.check_dimensions(self.rows, b.columns)?;
+        if factors.singular {
+            return Err("Matrix is singular, cannot solve system".to_string());
+        }
+
+        let n = self.rows;
+        let lu = &factors.lu;
+        let mut x = Matrix::zeros(n, b.columns);
+        for col in 0..b.columns {
+            // Forward substitution L y = P b (unit diagonal)
+            let mut y: Vec<f64> = factors.permutation.iter().map(|&row| b.data[row * b.columns + col]).collect();
+            for i in 0..n {
+                y[i] -= (0..i).map(|k| lu.data[i * n + k] * y[k]).sum::<f64>();
+            }
+
+            // Back substitution U x = y
+            for i in (0..n).rev() {
+                let tail: f64 = (i + 1..n).map(|k| lu.data[i * n + k] * y[k]).sum();
+                y[i] = (y[i] - tail) / lu.data[i * n + i];
+            }
+
+            for (i, value) in y.into_iter().enumerate() {
+                x.data[i * b.columns + col] = value;
+            }
+        }
+
+        Ok(x)
+    }
+
+    /// Solves the linear system `A X = B` with Gauss-Seidel iteration
+    ///
+    /// Starts from `X = 0` and sweeps the rows in order, using each updated
+    /// component immediately. Convergence is guaranteed for strictly diagonally
+    /// dominant and for symmetric positive-definite `A`.
+    ///
+    /// # Arguments
+    /// * `b` - Right-hand side with `rows` equal to the size of `A`
+    /// * `iterations` - Maximum number of sweeps
+    /// * `tol` - The iteration stops once the Frobenius norm of `B - A X` is below this
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing `X`, with the same shape as `b`
+    /// - `Err` if `A` isn't square, `b` has the wrong number of rows, a diagonal
+    ///   entry is zero, or the residual is still above `tol` after `iterations` sweeps
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix { data: vec![4.0, 1.0, 1.0, 3.0], rows: 2, columns: 2 };
+    /// let b = Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 };
+    /// let x = a.solve_gauss_seidel(&b, 100, 1e-12).unwrap();
+    /// assert!((x.data[0] - 1.0 / 11.0).abs() < 1e-12 && (x.data[1] - 7.0 / 11.0).abs() < 1e-12);
+    /// ```
+    pub fn solve_gauss_seidel(&self, b: &Matrix, iterations: usize, tol: f64) -> Result<Matrix, String> {
+        self.check_square()?;
+        b.check_dimensions(self.rows, b.columns)?;
+
+        let n = self.rows;
+        if (0..n).any(|i| self.data[i * n + i] == 0.0) {
+            return Err("Gauss-Seidel requires a non-zero diagonal".to_string());
+        }
+
+        let mut x = Matrix::zeros(n, b.columns);
+        for _ in 0..iterations {
+            for col in 0..b.columns {
+                for i in 0..n {
+                    let off_diagonal: f64 = (0..n)
+                        .filter(|&j| j != i)
+                        .map(|j| self.data[i * n + j] * x.data[j * b.columns + col])
+                        .sum();
+                    x.data[i * b.columns + col] = (b.data[i * b.columns + col] - off_diagonal) / self.data[i * n + i];
+                }
+            }
+
+            if b.subtraction(&self.multiply(&x)?)?.frobenius_norm() < tol {
+                return Ok(x);
+            }
+        }
+
+        Err(format!("Gauss-Seidel did not converge in {} iterations", iterations))
+    }
+
+    /// Computes the Moore-Penrose pseudo-inverse of a full-rank matrix
+    ///
+    /// Uses the normal equations: `(A^T A)^-1 A^T` when `rows >= columns` (a left
+    /// inverse) and `A^T (A A^T)^-1` for wide matrices (a right inverse). The Gram
+    /// matrix is solved against rather than inverted. For square invertible
+    /// matrices this is the ordinary inverse.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the `columns x rows` pseudo-inverse
+    /// - `Err` if the Gram matrix is singular, i.e. the matrix lacks full rank
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix { data: vec![1.0, 1.0], rows: 2, columns: 1 };
+    /// assert_eq!(a.pseudo_inverse().unwrap().data, vec![0.5, 0.5]);
+    /// ```
+    pub fn pseudo_inverse(&self) -> Result<Matrix, String> {
+        let transpose = self.transpose();
+
+        if self.rows >= self.columns {
+            transpose.multiply(self)?.solve(&transpose)
+        } else {
+            // A A^T is symmetric, so A^T (A A^T)^-1 = ((A A^T)^-1 A)^T
+            Ok(self.multiply(&transpose)?.solve(self)?.transpose())
+        }
+    }
+
+    /// Computes the Schur complement of the leading block
+    ///
+    /// Partitions the matrix as `[[A, B], [C, D]]` with `A` of size
+    /// `block_size x block_size` and returns `D - C A^-1 B`. `A^-1 B` is found with
+    /// [`Matrix::solve`] rather than by forming the inverse.
+    ///
+    /// # Arguments
+    /// * `block_size` - Size of the leading block `A`, strictly between 0 and `rows`
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the `(n - block_size) x (n - block_size)` complement
+    /// - `Err` if matrix isn't square, `block_size` is out of range, or `A` is singular
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![2.0, 1.0, 4.0, 3.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.schur_complement(1).unwrap().data, vec![1.0]);
+    /// ```
+    pub fn schur_complement(&self, block_size: usize) -> Result<Matrix, String> {
+        self.check_square()?;
+        let n = self.rows;
+        if block_size == 0 || block_size >= n {
+            return Err(format!(
+                "Block size must be between 1 and {} for a {}x{} matrix, got {}",
+                n.saturating_sub(1), n, n, block_size
+            ));
+        }
+
+        let a = self.block(0..block_size, 0..block_size);
+        let b = self.block(0..block_size, block_size..n);
+        let c = self.block(block_size..n, 0..block_size);
+        let d = self.block(block_size..n, block_size..n);
+
+        let a_inv_b = a.solve(&b)?;
+        d.subtraction(&c.multiply(&a_inv_b)?)
+    }
+
+    /// Computes the eigenvalues of a symmetric matrix
+    ///
+    /// Uses cyclic Jacobi rotations without accumulating eigenvectors, which is
+    /// cheaper when only the spectrum is needed.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<f64>)` containing the eigenvalues sorted in descending order
+    /// - `Err` if matrix isn't square or isn't symmetric within `1e-9`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[3.0, 1.0, 2.0]);
+    /// assert_eq!(m.eigenvalues_symmetric().unwrap(), vec![3.0, 2.0, 1.0]);
+    /// ```
+    pub fn eigenvalues_symmetric(&self) -> Result<Vec<f64>, String> {
+        self.check_square()?;
+        if !self.is_symmetric(EIGEN_SYMMETRY_TOLERANCE) {
+            return Err("Matrix is not symmetric, use diagonalize for general matrices".to_string());
+        }
+
+        let mut eigenvalues = self.jacobi_eigen(None);
+        eigenvalues.sort_by(|a, b| b.total_cmp(a));
+        Ok(eigenvalues)
+    }
+
+    /// Computes the Gershgorin discs that contain the eigenvalues
+    ///
+    /// Every eigenvalue lies in at least one disc centered at `a_ii` with radius
+    /// `Σ_{j≠i} |a_ij|`. This localizes the spectrum without running a solver.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<(f64, f64)>)` containing `(center, radius)` for each row
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![4.0, -1.0, 2.0, 5.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.gershgorin_bounds().unwrap(), vec![(4.0, 1.0), (5.0, 2.0)]);
+    /// ```
+    pub fn gershgorin_bounds(&self) -> Result<Vec<(f64, f64)>, String> {
+        self.check_square()?;
+
+        Ok((0..self.rows)
+            .map(|i| {
+                let radius = self[i]
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, x)| x.abs())
+                    .sum();
+                (self[i][i], radius)
+            })
+            .collect())
+    }
+
+    /// Computes the thin singular value decomposition `A = U * diag(s) * V^T`
+    ///
+    /// Uses one-sided (Hestenes) Jacobi rotations, which orthogonalize the columns
+    /// of `A` directly instead of forming `A^T A` and so keep full relative accuracy
+    /// for small singular values. Wide matrices are handled by decomposing `A^T`.
+    ///
+    /// # Returns
+    /// - `Ok((U, s, V^T))` where, with `k = min(rows, columns)`, `U` is `rows x k`,
+    ///   `s` holds the `k` singular values in descending order and `V^T` is `k x columns`
+    /// - `Err` if the matrix is empty
+    ///
+    /// # Notes
+    /// - Columns of `U` belonging to zero singular values are left as zero vectors
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![3.0, 0.0, 0.0, -2.0], rows: 2, columns: 2 };
+    /// let (_, s, _) = m.svd().unwrap();
+    /// assert!((s[0] - 3.0).abs() < 1e-12 && (s[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn svd(&self) -> Result<(Matrix, Vec<f64>, Matrix), String> {
+        if self.data.is_empty() {
+            return Err("SVD not defined for empty matrices".to_string());
+        }
+
+        if self.rows < self.columns {
+            // A^T = U' S V'^T  =>  A = V' S U'^T
+            let (u, s, vt) = self.transpose().one_sided_jacobi();
+            return Ok((vt.transpose(), s, u.transpose()));
+        }
+
+        Ok(self.one_sided_jacobi())
+    }
+
+    /// Diagonalizes a matrix with real eigenvalues as `A = P * D * P^-1`
+    ///
+    /// Symmetric matrices use Jacobi rotations and yield an orthogonal `P`. Other
+    /// matrices get their eigenvalues from shifted QR iteration; the eigenvectors of
+    /// each (possibly repeated) eigenvalue `λ` are then read off as the null space of
+    /// `A - λI` from its SVD. Matrix functions follow as `P * f(D) * P^-1`.
+    ///
+    /// # Returns
+    /// - `Ok((P, D))` with the eigenvectors as the columns of `P` and the matching
+    ///   eigenvalues on the diagonal of `D`, sorted in descending order
+    /// - `Err` if the matrix isn't square, has complex eigenvalues, or is defective
+    ///   (has fewer independent eigenvectors than its size)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![4.0, 1.0, 2.0, 3.0], rows: 2, columns: 2 };
+    /// let (p, d) = m.diagonalize().unwrap();
+    /// assert!((d.data[0] - 5.0).abs() < 1e-10 && (d.data[3] - 2.0).abs() < 1e-10);
+    /// let back = p.multiply(&d).unwrap().multiply(&p.inverse().unwrap()).unwrap();
+    /// assert!(back.data.iter().zip(m.data.iter()).all(|(a, b)| (a - b).abs() < 1e-10));
+    /// ```
+    pub fn diagonalize(&self) -> Result<(Matrix, Matrix), String> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let scale = self.data.iter().fold(0.0_f64, |max, &x| max.max(x.abs()));
+        let symmetric = (0..n).all(|i| {
+            (i + 1..n).all(|j| (self.data[i * n + j] - self.data[j * n + i]).abs() <= SYMMETRY_TOLERANCE * scale)
+        });
+
+        if symmetric {
+            let mut vectors = Matrix::identity(n);
+            let eigenvalues = self.jacobi_eigen(Some(&mut vectors));
+            let mut order: Vec<usize> = (0..n).collect();
+            order.sort_by(|&a, &b| eigenvalues[b].total_cmp(&eigenvalues[a]));
+
+            let mut p = Matrix::zeros(n, n);
+            for (dst, &src) in order.iter().enumerate() {
+                for k in 0..n {
+                    p.data[k * n + dst] = vectors.data[k * n + src];
+                }
+            }
+            let sorted: Vec<f64> = order.iter().map(|&i| eigenvalues[i]).collect();
+            return Ok((p, Matrix::diagonal(&sorted)));
+        }
+
+        let mut eigenvalues = self.real_eigenvalues()?;
+        eigenvalues.sort_by(|a, b| b.total_cmp(a));
+
+        // Eigenvalues closer than this are treated as one repeated eigenvalue
+        let tolerance = EIGENVALUE_CLUSTER_TOLERANCE * scale;
+        let mut p = Matrix::zeros(n, n);
+        let mut start = 0;
+        while start < n {
+            let mut end = start + 1;
+            while end < n && eigenvalues[start] - eigenvalues[end] <= tolerance {
+                end += 1;
+            }
+            let multiplicity = end - start;
+            let lambda = eigenvalues[start..end].iter().sum::<f64>() / multiplicity as f64;
+
+            // Right singular vectors of the smallest singular values span the null space
+            let (_, sigma, vt) = self.diag_add(-lambda)?.svd()?;
+            if sigma[n - multiplicity] > tolerance {
+                return Err(format!(
+                    "Matrix is defective (eigenvalue {} lacks independent eigenvectors), cannot diagonalize",
+                    lambda
+                ));
+            }
+            for t in 0..multiplicity {
+                for k in 0..n {
+                    p.data[k * n + start + t] = vt.data[(n - multiplicity + t) * n + k];
+                }
+            }
+            start = end;
+        }
+
+        Ok((p, Matrix::diagonal(&eigenvalues)))
+    }
+
+    /// Computes the closest orthogonal matrix in the Frobenius norm
+    ///
+    /// This is the orthogonal factor `U * V^T` of the polar decomposition, taken from
+    /// the SVD `A = U * diag(s) * V^T`. It is the standard way to re-orthonormalize a
+    /// rotation matrix that has drifted through accumulated rounding.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the orthogonal factor
+    /// - `Err` if matrix isn't square, is empty, or is singular
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let drifted = Matrix { data: vec![1.001, 0.002, -0.001, 0.998], rows: 2, columns: 2 };
+    /// let fixed = drifted.nearest_orthogonal().unwrap();
+    /// assert!(fixed.is_orthogonal(1e-12));
+    /// ```
+    pub fn nearest_orthogonal(&self) -> Result<Matrix, String> {
+        self.check_square()?;
+
+        let (u, s, vt) = self.svd()?;
+        if s.last().is_some_and(|&smallest| smallest <= f64::EPSILON * s[0] * self.rows as f64) {
+            return Err("Matrix is singular, orthogonal polar factor is not unique".to_string());
+        }
+
+        u.multiply(&vt)
+    }
+
+    /// Finds the rotation that best aligns one point set with another (Kabsch)
+    ///
+    /// Each row is a point. Both sets are centered on their centroids, and the
+    /// rotation `R` minimizing `Σ |R p_i - q_i|²` is read off the SVD
+    /// `P^T Q = U S V^T` as `R = V D U^T`, where `D` flips the last axis if needed
+    /// so that `det(R) = +1` (a proper rotation, never a reflection). Apply it to a
+    /// point set as `P * R^T`.
+    ///
+    /// # Arguments
+    /// * `other` - Target points, with the same shape as `self`
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the `columns x columns` rotation
+    /// - `Err` if the shapes differ, the sets are empty, or all points of a set coincide
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let p = Matrix { data: vec![1.0, 0.0, 0.0, 1.0, -1.0, 0.0], rows: 3, columns: 2 };
+    /// let q = Matrix { data: vec![0.0, 1.0, -1.0, 0.0, 0.0, -1.0], rows: 3, columns: 2 };
+    /// let r = p.kabsch(&q).unwrap();
+    /// assert!((r.data[2] - 1.0).abs() < 1e-12); // 90 degree turn: R = [[0, -1], [1, 0]]
+    /// ```
+    pub fn kabsch(&self, other: &Matrix) -> Result<Matrix, String> {
+        self.check_dimensions_match(other)?;
+        if self.data.is_empty() {
+            return Err("Kabsch alignment needs at least one point".to_string());
+        }
+
+        let d = self.columns;
+        let p = self.subtract_row_vector(&self.column_means())?;
+        let q = other.subtract_row_vector(&other.column_means())?;
+        let (mut u, s, vt) = p.transpose().multiply(&q)?.svd()?;
+        if s[0] == 0.0 {
+            return Err("Point sets have no spread, rotation is undefined".to_string());
+        }
+
+        // Planar or collinear sets leave columns of U undetermined; complete them
+        // to an orthonormal basis so that U (and hence R) stays orthogonal
+        let tolerance = d as f64 * f64::EPSILON * s[0];
+        let missing: Vec<usize> = (0..d).filter(|&j| s[j] <= tolerance).collect();
+        for &j in &missing {
+            (0..d).for_each(|k| u.data[k * d + j] = 0.0);
+        }
+        for &j in &missing {
+            let candidates = (0..d).map(|axis| {
+                let mut w: Vec<f64> = (0..d).map(|k| if k == axis { 1.0 } else { 0.0 }).collect();
+                for c in (0..d).filter(|&c| c != j) {
+                    let projection = u.data[axis * d + c];
+                    (0..d).for_each(|k| w[k] -= projection * u.data[k * d + c]);
+                }
+                w
+            });
+            let best = candidates
+                .max_by(|a, b| a.iter().map(|x| x * x).sum::<f64>().total_cmp(&b.iter().map(|x| x * x).sum::<f64>()))
+                .unwrap_or_default();
+            let norm = best.iter().map(|x| x * x).sum::<f64>().sqrt();
+            (0..d).for_each(|k| u.data[k * d + j] = best[k] / norm);
+        }
+
+        let v = vt.transpose();
+        let ut = u.transpose();
+        let mut correction = Matrix::identity(d);
+        if v.multiply(&ut)?.determinant()? < 0.0 {
+            correction.data[d * d - 1] = -1.0;
+        }
+
+        v.multiply(&correction)?.multiply(&ut)
+    }
+
+    /// Computes the thin QR decomposition `A = Q R` with modified Gram-Schmidt
+    ///
+    /// Each column is orthogonalized against the previous `Q` columns one at a time
+    /// (subtracting projections from the running residual), which keeps `Q` much
+    /// closer to orthonormal than classical Gram-Schmidt.
+    ///
+    /// # Returns
+    /// - `Ok((Q, R))` with `Q` of shape `rows x columns` having orthonormal columns
+    ///   and `R` upper triangular `columns x columns` with a positive diagonal
+    /// - `Err` if there are more columns than rows or the columns are (numerically)
+    ///   linearly dependent; see [`Matrix::qr_column_pivoted`] for rank-deficient input
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![3.0, 1.0, 4.0, 2.0], rows: 2, columns: 2 };
+    /// let (q, r) = m.qr_decomposition().unwrap();
+    /// assert!((r.data[0] - 5.0).abs() < 1e-12);
+    /// let back = q.multiply(&r).unwrap();
+    /// assert!(back.data.iter().zip(m.data.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    pub fn qr_decomposition(&self) -> Result<(Matrix, Matrix), String> {
+        let (m, n) = (self.rows, self.columns);
+        if n > m {
+            return Err(format!(
+                "QR decomposition requires rows >= columns, got {}x{}",
+                m, n
+            ));
+        }
+
+        let column_norm = |j: usize| (0..m).map(|i| self.data[i * n + j].powi(2)).sum::<f64>().sqrt();
+
+        let mut q = self.clone();
+        let mut r = Matrix::zeros(n, n);
+        for k in 0..n {
+            let norm = (0..m).map(|i| q.data[i * n + k].powi(2)).sum::<f64>().sqrt();
+            // Dependent when little of the column survives, measured against its own
+            // original length so that columns of different scale are judged alike
+            if norm <= m as f64 * f64::EPSILON * column_norm(k) {
+                return Err(format!(
+                    "Column {} is linearly dependent on the previous columns",
+                    k
+                ));
+            }
+            r.data[k * n + k] = norm;
+            for i in 0..m {
+                q.data[i * n + k] /= norm;
+            }
+
+            // Remove the new direction from all remaining columns
+            for j in k + 1..n {
+                let projection: f64 = (0..m).map(|i| q.data[i * n + k] * q.data[i * n + j]).sum();
+                r.data[k * n + j] = projection;
+                for i in 0..m {
+                    q.data[i * n + j] -= projection * q.data[i * n + k];
+                }
+            }
+        }
+
+        Ok((q, r))
+    }
+
+    /// Computes a rank-revealing QR factorization with column pivoting
+    ///
+    /// Before each Householder step the remaining column with the largest norm
+    /// (below the rows already processed) is swapped into place, so the most
+    /// independent columns come first and `|R_kk|` is non-increasing. The numerical
+    /// rank is the number of diagonal entries with `|R_kk| > tol * |R_00|` for a
+    /// tolerance such as `max(rows, columns) * f64::EPSILON`.
+    ///
+    /// # Returns
+    /// - `Ok((Q, R, permutation))` with `Q` orthogonal (`rows x rows`), `R` upper
+    ///   triangular (`rows x columns`) and `A P = Q R`, where `permutation[j]` is the
+    ///   original column moved to position `j`
+    /// - `Err` if the matrix is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 3.0, 0.0, 4.0], rows: 2, columns: 2 };
+    /// let (_, r, permutation) = m.qr_column_pivoted().unwrap();
+    /// // Column 1 has norm 5 and is taken first
+    /// assert_eq!(permutation, vec![1, 0]);
+    /// assert!((r.data[0].abs() - 5.0).abs() < 1e-12);
+    /// ```
+    pub fn qr_column_pivoted(&self) -> Result<(Matrix, Matrix, Vec<usize>), String> {
+        if self.data.is_empty() {
+            return Err("QR factorization not defined for empty matrices".to_string());
+        }
+
+        let (m, n) = (self.rows, self.columns);
+        let mut r = self.clone();
+        let mut q = Matrix::identity(m);
+        let mut permutation: Vec<usize> = (0..n).collect();
+
+        for k in 0..m.min(n) {
+            let residual_norm = |j: usize| (k..m).map(|i| r.data[i * n + j] * r.data[i * n + j]).sum::<f64>();
+            let pivot = (k..n)
+                .max_by(|&a, &b| residual_norm(a).total_cmp(&residual_norm(b)))
+                .unwrap_or(k);
+            if pivot != k {
+                r.swap_columns(pivot, k);
+                permutation.swap(pivot, k);
+            }
+
+            Self::householder_step(&mut r, &mut q, k);
+        }
+
+        Ok((q, r, permutation))
+    }
+
+    /// One-sided Jacobi SVD for matrices with `rows >= columns`
+    ///
+    /// # Returns
+    /// `(U, s, V^T)` with singular values sorted in descending order
+    fn one_sided_jacobi(&self) -> (Matrix, Vec<f64>, Matrix) {
+        let (m, n) = (self.rows, self.columns);
+        let mut u = self.clone();
+        let mut v = Matrix::identity(n);
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                    for k in 0..m {
+                        let ukp = u.data[k * n + p];
+                        let ukq = u.data[k * n + q];
+                        alpha += ukp * ukp;
+                        beta += ukq * ukq;
+                        gamma += ukp * ukq;
+                    }
+                    if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    // Rotation that makes columns p and q orthogonal
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for k in 0..m {
+                        let ukp = u.data[k * n + p];
+                        let ukq = u.data[k * n + q];
+                        u.data[k * n + p] = c * ukp - s * ukq;
+                        u.data[k * n + q] = s * ukp + c * ukq;
+                    }
+                    for k in 0..n {
+                        let vkp = v.data[k * n + p];
+                        let vkq = v.data[k * n + q];
+                        v.data[k * n + p] = c * vkp - s * vkq;
+                        v.data[k * n + q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        // Singular values are the norms of the orthogonalized columns
+        let sigma: Vec<f64> = (0..n)
+            .map(|j| (0..m).map(|k| u.data[k * n + j] * u.data[k * n + j]).sum::<f64>().sqrt())
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| sigma[b].total_cmp(&sigma[a]));
+
+        let mut u_sorted = Matrix::zeros(m, n);
+        let mut vt = Matrix::zeros(n, n);
+        for (dst, &src) in order.iter().enumerate() {
+            if sigma[src] > 0.0 {
+                for k in 0..m {
+                    u_sorted.data[k * n + dst] = u.data[k * n + src] / sigma[src];
+                }
+            }
+            for k in 0..n {
+                vt.data[dst * n + k] = v.data[k * n + src];
+            }
+        }
+
+        let singular_values = order.iter().map(|&j| sigma[j]).collect();
+        (u_sorted, singular_values, vt)
+    }
+
+    /// Diagonalizes a symmetric matrix with cyclic Jacobi rotations
+    ///
+    /// # Arguments
+    /// * `vectors` - When given, accumulates the rotations so that on return its
+    ///   columns are the eigenvectors (pass an identity matrix of the same size)
+    ///
+    /// # Returns
+    /// Eigenvalues in the order matching the eigenvector columns (unsorted)
+    pub(crate) fn jacobi_eigen(&self, mut vectors: Option<&mut Matrix>) -> Vec<f64> {
+        let n = self.rows;
+        let mut a = self.clone();
+        let scale: f64 = a.data.iter().map(|x| x * x).sum();
+
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let off_diagonal: f64 = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a.data[i * n + j] * a.data[i * n + j])
+                .sum();
+            if off_diagonal <= f64::EPSILON * f64::EPSILON * scale {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a.data[p * n + q];
+                    if apq == 0.0 {
+                        continue;
+                    }
+
+                    // Rotation angle that zeroes a[p][q]
+                    let theta = (a.data[q * n + q] - a.data[p * n + p]) / (2.0 * apq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    // A <- A * J
+                    for k in 0..n {
+                        let akp = a.data[k * n + p];
+                        let akq = a.data[k * n + q];
+                        a.data[k * n + p] = c * akp - s * akq;
+                        a.data[k * n + q] = s * akp + c * akq;
+                    }
+                    // A <- J^T * A
+                    for k in 0..n {
+                        let apk = a.data[p * n + k];
+                        let aqk = a.data[q * n + k];
+                        a.data[p * n + k] = c * apk - s * aqk;
+                        a.data[q * n + k] = s * apk + c * aqk;
+                    }
+
+                    if let Some(v) = vectors.as_deref_mut() {
+                        for k in 0..n {
+                            let vkp = v.data[k * n + p];
+                            let vkq = v.data[k * n + q];
+                            v.data[k * n + p] = c * vkp - s * vkq;
+                            v.data[k * n + q] = s * vkp + c * vkq;
+                        }
+                    }
+                }
+            }
+        }
+
+        (0..n).map(|i| a.data[i * n + i]).collect()
+    }
+
+    /// Computes all eigenvalues of a general square matrix with shifted QR iteration
+    ///
+    /// Deflates one eigenvalue when the last row vanishes left of the diagonal, or two
+    /// when the trailing 2x2 block decouples, using Wilkinson shifts in between.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<f64>)` containing the eigenvalues (unsorted)
+    /// - `Err` if a complex conjugate pair is found or the iteration doesn't converge
+    fn real_eigenvalues(&self) -> Result<Vec<f64>, String> {
+        let mut a = self.clone();
+        let mut eigenvalues = Vec::with_capacity(self.rows);
+        let mut iterations = 0;
+
+        while a.rows > 0 {
+            let m = a.rows;
+            if m == 1 {
+                eigenvalues.push(a.data[0]);
+                break;
+            }
+
+            let at = |i: usize, j: usize| a.data[i * m + j];
+            // The trailing `size x size` block decouples when everything to its left is negligible
+            let threshold = f64::EPSILON * a.data.iter().fold(0.0_f64, |max, &x| max.max(x.abs()));
+            let decoupled = |size: usize| (m - size..m).all(|i| (0..m - size).all(|j| at(i, j).abs() <= threshold));
+
+            if decoupled(1) {
+                eigenvalues.push(at(m - 1, m - 1));
+                a = a.block(0..m - 1, 0..m - 1);
+                iterations = 0;
+                continue;
+            }
+
+            // Eigenvalues of the trailing 2x2 block [[p, q], [r, s]]
+            let (p, q, r, s) = (at(m - 2, m - 2), at(m - 2, m - 1), at(m - 1, m - 2), at(m - 1, m - 1));
+            let half = (p + s) / 2.0;
+            let mut discriminant = (p - s) * (p - s) / 4.0 + q * r;
+            let block_scale = p.abs() + q.abs() + r.abs() + s.abs();
+            if discriminant < 0.0 && -discriminant <= f64::EPSILON * block_scale * block_scale {
+                discriminant = 0.0;
+            }
+
+            if decoupled(2) {
+                if discriminant < 0.0 {
+                    return Err("Matrix has complex eigenvalues".to_string());
+                }
+                let root = discriminant.sqrt();
+                eigenvalues.push(half + root);
+                eigenvalues.push(half - root);
+                a = a.block(0..m - 2, 0..m - 2);
+                iterations = 0;
+                continue;
+            }
+
+            if iterations == MAX_QR_ITERATIONS {
+                return Err("QR iteration did not converge (complex eigenvalues?)".to_string());
+            }
+            iterations += 1;
+
+            // Wilkinson shift, with an occasional exceptional shift to break cycles
+            let shift = if iterations % 10 == 0 {
+                s + at(m - 1, m - 2).abs()
+            } else if discriminant >= 0.0 {
+                let root = discriminant.sqrt();
+                if (half + root - s).abs() < (half - root - s).abs() { half + root } else { half - root }
+            } else {
+                s
+            };
+
+            let (q_factor, r_factor) = a.diag_add(-shift)?.householder_qr();
+            a = r_factor.multiply(&q_factor)?.diag_add(shift)?;
+        }
+
+        Ok(eigenvalues)
+    }
+
+    /// Factors the matrix as `Q * R` using Householder reflections
+    ///
+    /// # Returns
+    /// `(Q, R)` with `Q` orthogonal (`rows x rows`) and `R` upper triangular (`rows x columns`)
+    fn householder_qr(&self) -> (Matrix, Matrix) {
+        let mut r = self.clone();
+        let mut q = Matrix::identity(self.rows);
+
+        for k in 0..self.columns.min(self.rows) {
+            Self::householder_step(&mut r, &mut q, k);
+        }
+
+        (q, r)
+    }
+
+    /// Applies the Householder reflection that zeroes column `k` of `r` below the diagonal
+    ///
+    /// The reflection is applied to `r` from the left and accumulated into `q` from
+    /// the right, so `q * r` is unchanged.
+    fn householder_step(r: &mut Matrix, q: &mut Matrix, k: usize) {
+        let (m, n) = (r.rows, r.columns);
+        if k + 1 >= m {
+            return;
+        }
+
+        let x: Vec<f64> = (k..m).map(|i| r.data[i * n + k]).collect();
+        let norm = x.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            return;
+        }
+
+        // Reflect x onto -sign(x0) * ‖x‖ * e1 to avoid cancellation
+        let alpha = if x[0] > 0.0 { -norm } else { norm };
+        let mut v = x;
+        v[0] -= alpha;
+        let vv: f64 = v.iter().map(|e| e * e).sum();
+        if vv == 0.0 {
+            return;
+        }
+
+        for j in 0..n {
+            let dot: f64 = v.iter().enumerate().map(|(l, &vl)| vl * r.data[(k + l) * n + j]).sum();
+            for (l, &vl) in v.iter().enumerate() {
+                r.data[(k + l) * n + j] -= 2.0 * dot * vl / vv;
+            }
+        }
+        for i in 0..m {
+            let dot: f64 = v.iter().enumerate().map(|(l, &vl)| vl * q.data[i * m + k + l]).sum();
+            for (l, &vl) in v.iter().enumerate() {
+                q.data[i * m + k + l] -= 2.0 * dot * vl / vv;
+            }
+        }
+        for i in k + 1..m {
+            r.data[i * n + k] = 0.0;
+        }
+    }
+}
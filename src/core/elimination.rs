@@ -0,0 +1,211 @@
+use crate::core::Matrix;
+
+impl Matrix {
+    /// Computes the reduced row echelon form
+    ///
+    /// Performs Gauss-Jordan elimination with partial pivoting. Works on
+    /// rectangular matrices. Pivots no larger than `f64::EPSILON`, scaled by
+    /// `max(rows, columns) * ‖A‖∞` when that is bigger, are treated as zero and
+    /// the rest of their column is cleared.
+    ///
+    /// # Returns
+    /// The reduced row echelon form, with zero rows at the bottom
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 2.0, 4.0, 7.0], rows: 2, columns: 3 };
+    /// assert_eq!(m.rref().data, vec![1.0, 2.0, 0.0, 0.0, 0.0, 1.0]);
+    /// ```
+    pub fn rref(&self) -> Self {
+        self.gauss_jordan(None)
+    }
+
+    /// Computes the reduced row echelon form using full (complete) pivoting
+    ///
+    /// At each step the largest remaining entry is moved onto the diagonal by a
+    /// row swap and a column swap. Entries no larger than
+    /// `max(rows, columns) * f64::EPSILON * max|a_ij|` are treated as zero, which
+    /// makes the rank robust for badly scaled or nearly dependent inputs.
+    ///
+    /// # Returns
+    /// `(R, row_permutation, column_permutation)` where:
+    /// - `R` is the RREF of the permuted matrix `P A Q`, of the form `[[I, F], [0, 0]]`;
+    ///   the rank is the number of leading ones on its diagonal
+    /// - `row_permutation[i]` is the original row moved to position `i`
+    /// - `column_permutation[j]` is the original column moved to position `j`,
+    ///   so column `j` of `R` corresponds to column `column_permutation[j]` of `self`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    /// let (r, rows, cols) = m.rref_full_pivot();
+    /// assert_eq!(rows, vec![1, 0]);
+    /// assert_eq!(cols, vec![1, 0]);
+    /// assert_eq!(r.data, vec![1.0, 0.5, 0.0, 0.0]);
+    /// ```
+    pub fn rref_full_pivot(&self) -> (Self, Vec<usize>, Vec<usize>) {
+        let (rows, columns) = (self.rows, self.columns);
+        let mut m = self.clone();
+        let mut row_permutation: Vec<usize> = (0..rows).collect();
+        let mut column_permutation: Vec<usize> = (0..columns).collect();
+
+        let max_abs = self.data.iter().fold(0.0_f64, |max, &x| max.max(x.abs()));
+        let tolerance = rows.max(columns) as f64 * f64::EPSILON * max_abs;
+
+        let mut rank = 0;
+        for k in 0..rows.min(columns) {
+            // Largest entry of the trailing submatrix
+            let mut pivot = (k, k);
+            for i in k..rows {
+                for j in k..columns {
+                    if m.data[i * columns + j].abs() > m.data[pivot.0 * columns + pivot.1].abs() {
+                        pivot = (i, j);
+                    }
+                }
+            }
+
+            if m.data[pivot.0 * columns + pivot.1].abs() <= tolerance {
+                break;
+            }
+
+            m.swap_rows(k, pivot.0);
+            row_permutation.swap(k, pivot.0);
+            m.swap_columns(k, pivot.1);
+            column_permutation.swap(k, pivot.1);
+
+            // Scale pivot row to a leading one
+            let pivot_value = m.data[k * columns + k];
+            for c in k..columns {
+                m.data[k * columns + c] /= pivot_value;
+            }
+
+            // Eliminate this column from every other row
+            for row in (0..rows).filter(|&r| r != k) {
+                let factor = m.data[row * columns + k];
+                if factor != 0.0 {
+                    for c in k..columns {
+                        m.data[row * columns + c] -= factor * m.data[k * columns + c];
+                    }
+                }
+            }
+
+            rank += 1;
+        }
+
+        // Whatever remains below the pivots is numerical noise
+        for value in &mut m.data[rank * columns..] {
+            *value = 0.0;
+        }
+
+        (m, row_permutation, column_permutation)
+    }
+
+    /// Reduces the matrix to reduced row echelon form, recording every step
+    ///
+    /// Performs Gauss-Jordan elimination with partial pivoting and logs each
+    /// elementary row operation in a human-readable form, using 1-based row names:
+    /// - `"R1 <-> R2"` swaps two rows
+    /// - `"R1 /= 3"` scales a row to make its pivot one
+    /// - `"R2 -= 3*R1"` / `"R2 += 3*R1"` adds a multiple of one row to another
+    ///
+    /// Pivots are treated as zero with the same tolerance as [`Matrix::rref`].
+    ///
+    /// # Returns
+    /// `(R, steps)` where `R` is the reduced row echelon form and `steps` the operation log
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![3.0, 6.0, 1.0, 4.0], rows: 2, columns: 2 };
+    /// let (r, steps) = m.reduce_with_steps();
+    /// assert_eq!(r, Matrix::identity(2));
+    /// assert_eq!(steps, vec!["R1 /= 3", "R2 -= R1", "R2 /= 2", "R1 -= 2*R2"]);
+    /// ```
+    pub fn reduce_with_steps(&self) -> (Self, Vec<String>) {
+        let mut steps = Vec::new();
+        let reduced = self.gauss_jordan(Some(&mut steps));
+        (reduced, steps)
+    }
+
+    /// Gauss-Jordan elimination with partial pivoting
+    ///
+    /// # Arguments
+    /// * `steps` - When given, receives a log of the elementary row operations
+    ///
+    /// # Returns
+    /// The reduced row echelon form of the matrix
+    fn gauss_jordan(&self, mut steps: Option<&mut Vec<String>>) -> Self {
+        let (rows, columns) = (self.rows, self.columns);
+        let mut m = self.clone();
+        let mut pivot_row = 0;
+
+        // Round-off left in dependent rows is bounded by the rows it was combined
+        // from, so it grows with the size and the largest absolute row sum
+        let tolerance = f64::EPSILON.max(rows.max(columns) as f64 * f64::EPSILON * self.norm_inf());
+
+        for col in 0..columns {
+            if pivot_row == rows {
+                break;
+            }
+
+            // Largest entry in this column at or below the current pivot row
+            let best = (pivot_row..rows)
+                .max_by(|&a, &b| {
+                    m.data[a * columns + col]
+                        .abs()
+                        .total_cmp(&m.data[b * columns + col].abs())
+                })
+                .unwrap_or(pivot_row);
+
+            if m.data[best * columns + col].abs() <= tolerance {
+                // No usable pivot: clear the noise so the column reads as zero
+                for row in pivot_row..rows {
+                    m.data[row * columns + col] = 0.0;
+                }
+                continue;
+            }
+
+            if best != pivot_row {
+                m.swap_rows(best, pivot_row);
+                if let Some(log) = steps.as_deref_mut() {
+                    log.push(format!("R{} <-> R{}", pivot_row + 1, best + 1));
+                }
+            }
+
+            let pivot = m.data[pivot_row * columns + col];
+            if pivot != 1.0 {
+                for c in col..columns {
+                    m.data[pivot_row * columns + c] /= pivot;
+                }
+                if let Some(log) = steps.as_deref_mut() {
+                    log.push(format!("R{} /= {}", pivot_row + 1, pivot));
+                }
+            }
+
+            for row in (0..rows).filter(|&r| r != pivot_row) {
+                let factor = m.data[row * columns + col];
+                if factor == 0.0 {
+                    continue;
+                }
+
+                for c in col..columns {
+                    m.data[row * columns + c] -= factor * m.data[pivot_row * columns + c];
+                }
+                // The pivot column is now exactly zero outside the pivot row
+                m.data[row * columns + col] = 0.0;
+
+                if let Some(log) = steps.as_deref_mut() {
+                    let (op, magnitude) = if factor < 0.0 { ("+=", -factor) } else { ("-=", factor) };
+                    let multiple = if magnitude == 1.0 { String::new() } else { format!("{}*", magnitude) };
+                    log.push(format!("R{} {} {}R{}", row + 1, op, multiple, pivot_row + 1));
+                }
+            }
+
+            pivot_row += 1;
+        }
+
+        m
+    }
+}
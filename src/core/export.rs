@@ -0,0 +1,50 @@
+use std::io::Write;
+
+use crate::core::Matrix;
+
+impl Matrix {
+    /// Writes the matrix as a binary (P5) grayscale PGM image
+    ///
+    /// Each element becomes one pixel, with `columns` as the image width and `rows`
+    /// as its height. Values are mapped linearly so the smallest element is black (0)
+    /// and the largest is white (255). A matrix whose finite elements are all equal
+    /// is written as all black; `NaN` elements are written as black and infinities
+    /// saturate to the ends of the range.
+    ///
+    /// # Arguments
+    /// * `writer` - Destination for the image bytes
+    ///
+    /// # Returns
+    /// - `Ok(())` once the header and all pixels are written
+    /// - `Err` if writing fails
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![0.0, 1.0], rows: 1, columns: 2 };
+    /// let mut bytes = Vec::new();
+    /// m.to_pgm(&mut bytes).unwrap();
+    /// assert_eq!(bytes, b"P5\n2 1\n255\n\x00\xff");
+    /// ```
+    pub fn to_pgm(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let finite = self.data.iter().copied().filter(|x| x.is_finite());
+        let min = finite.clone().fold(f64::INFINITY, f64::min);
+        let max = finite.fold(f64::NEG_INFINITY, f64::max);
+        let range = max - min;
+
+        let pixels: Vec<u8> = self
+            .data
+            .iter()
+            .map(|&x| {
+                if x.is_nan() || range <= 0.0 {
+                    0
+                } else {
+                    (((x - min) / range).clamp(0.0, 1.0) * 255.0).round() as u8
+                }
+            })
+            .collect();
+
+        write!(writer, "P5\n{} {}\n255\n", self.columns, self.rows)?;
+        writer.write_all(&pixels)
+    }
+}
@@ -1,4 +1,19 @@
 use crate::core::Matrix;
+use std::ops::Range;
+
+/// Packed result of an LU factorization with partial pivoting
+///
+/// # Fields
+/// - `lu`: Combined factors; strictly lower part holds L (unit diagonal implied), upper part holds U
+/// - `permutation`: `permutation[i]` is the original row moved to row `i`
+/// - `swaps`: Number of row exchanges performed
+/// - `singular`: Whether a pivot was negligible relative to its original row and column
+pub(crate) struct LuFactors {
+    pub lu: Matrix,
+    pub permutation: Vec<usize>,
+    pub swaps: usize,
+    pub singular: bool,
+}
 
 impl Matrix {
     /// Checks if matrices are compatible for multiplication
@@ -11,7 +26,7 @@ impl Matrix {
     /// - `Err` with descriptive message if incompatible
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let a = Matrix::zeros(2, 3);  // 2x3
     /// let b = Matrix::zeros(3, 4);  // 3x4
@@ -41,7 +56,7 @@ impl Matrix {
     /// - `Err` with descriptive message if mismatch
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let m = Matrix::identity(2);
     /// assert!(m.check_dimensions(2, 2).is_ok());
@@ -67,7 +82,7 @@ impl Matrix {
     /// - `Err` with descriptive message if mismatch
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let a = Matrix::zeros(2, 3);
     /// let b = Matrix::zeros(2, 3);
@@ -93,7 +108,7 @@ impl Matrix {
     /// - `Err` with descriptive message for non-square
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let square = Matrix::identity(3);
     /// assert!(square.check_square().is_ok());
@@ -111,13 +126,35 @@ impl Matrix {
         Ok(())
     }
 
+    /// Computes the mean of every column
+    ///
+    /// # Returns
+    /// `1 x columns` row vector of column means (NaN for a matrix without rows)
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 6.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.column_means().data, vec![2.0, 4.0]);
+    /// ```
+    pub(crate) fn column_means(&self) -> Matrix {
+        let n = self.columns;
+        Matrix {
+            data: (0..n)
+                .map(|col| (0..self.rows).map(|row| self.data[row * n + col]).sum::<f64>() / self.rows as f64)
+                .collect(),
+            rows: 1,
+            columns: n,
+        }
+    }
+
     /// Checks if matrix is a vector (either row or column vector)
     ///
     /// # Returns
     /// `true` if matrix is 1×n or n×1, `false` otherwise
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let row_vec = Matrix::zeros(1, 3);
     /// let col_vec = Matrix::zeros(3, 1);
@@ -137,7 +174,7 @@ impl Matrix {
     /// `true` if matrix is valid 3D vector, `false` otherwise
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let valid = Matrix::zeros(3, 1);
     /// let invalid_size = Matrix::zeros(3, 2);
@@ -162,7 +199,7 @@ impl Matrix {
     /// - `Err` if dimensions mismatch
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let a = Matrix::ones(2, 2);
     /// let b = Matrix::diagonal(&[1.0, 2.0]);
@@ -198,7 +235,7 @@ impl Matrix {
     /// New matrix with operation results
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let m = Matrix::diagonal(&[1.0, 2.0]);
     /// let scaled = m.scalar_operation(2.0, |x, s| x * s);
@@ -226,7 +263,7 @@ impl Matrix {
     /// - Modifies matrix in-place
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// use nelab::Matrix;
     /// let mut m = Matrix::diagonal(&[1.0, 2.0]);
     /// m.swap_rows(0, 1);
@@ -243,4 +280,140 @@ impl Matrix {
             self.data.swap(idx1, idx2);
         }
     }
-}
\ No newline at end of file
+
+    /// Swaps two columns in the matrix
+    ///
+    /// # Arguments
+    /// * `col1` - First column index (0-based)
+    /// * `col2` - Second column index (0-based)
+    ///
+    /// # Notes
+    /// - Silently returns if either index is out of bounds
+    /// - Modifies matrix in-place
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use nelab::Matrix;
+    /// let mut m = Matrix::diagonal(&[1.0, 2.0]);
+    /// m.swap_columns(0, 1);
+    /// assert_eq!(m.data, vec![0.0, 1.0, 2.0, 0.0]);
+    /// ```
+    pub(crate) fn swap_columns(&mut self, col1: usize, col2: usize) {
+        if col1 >= self.columns || col2 >= self.columns {
+            return;
+        }
+
+        for row in 0..self.rows {
+            let idx1 = row * self.columns + col1;
+            let idx2 = row * self.columns + col2;
+            self.data.swap(idx1, idx2);
+        }
+    }
+
+    /// Copies the block at the given row and column ranges
+    ///
+    /// # Arguments
+    /// * `rows` - Row indices to copy (0-based, end exclusive)
+    /// * `columns` - Column indices to copy (0-based, end exclusive)
+    ///
+    /// # Notes
+    /// - Ranges are not validated; out-of-bounds ranges panic
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.block(1..2, 0..2).data, vec![3.0, 4.0]);
+    /// ```
+    pub(crate) fn block(&self, rows: Range<usize>, columns: Range<usize>) -> Matrix {
+        let (height, width) = (rows.len(), columns.len());
+        let data = rows
+            .flat_map(|row| self.data[row * self.columns + columns.start..row * self.columns + columns.end].to_vec())
+            .collect();
+
+        Matrix {
+            data,
+            rows: height,
+            columns: width,
+        }
+    }
+
+    /// Factors a square matrix as PA = LU using partial pivoting
+    ///
+    /// # Returns
+    /// - `Ok(LuFactors)` with the packed factors and pivoting information
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Notes
+    /// - A pivot no larger than `n * f64::EPSILON` times the smaller of the absolute
+    ///   sums of its original row and column flags the factorization as singular, so
+    ///   the test depends neither on the overall scale of the matrix nor on a single
+    ///   large off-diagonal entry
+    /// - Elimination only skips columns whose pivot is exactly zero, so `P A = L U`
+    ///   holds for tiny but invertible matrices too
+    ///
+    /// # Examples
+    /// ```ignore
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![0.0, 1.0, 1.0, 0.0], rows: 2, columns: 2 };
+    /// let factors = m.lu_factorize().unwrap();
+    /// assert_eq!(factors.swaps, 1);
+    /// assert!(!factors.singular);
+    /// ```
+    pub(crate) fn lu_factorize(&self) -> Result<LuFactors, String> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let mut lu = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+        let mut singular = false;
+
+        // A pivot counts as zero relative to the smaller of its original row and
+        // column, since one huge entry elsewhere in either says nothing about it
+        let row_sums: Vec<f64> = self.data.chunks(n.max(1)).map(|row| row.iter().map(|x| x.abs()).sum()).collect();
+        let column_sums: Vec<f64> = (0..n).map(|j| (0..n).map(|i| self.data[i * n + j].abs()).sum()).collect();
+        let tolerance = n as f64 * f64::EPSILON;
+
+        for col in 0..n {
+            // Pick the largest remaining entry in this column as pivot
+            let pivot_row = (col..n)
+                .max_by(|&a, &b| {
+                    lu.data[a * n + col]
+                        .abs()
+                        .total_cmp(&lu.data[b * n + col].abs())
+                })
+                .unwrap_or(col);
+
+            if pivot_row != col {
+                lu.swap_rows(pivot_row, col);
+                permutation.swap(pivot_row, col);
+                swaps += 1;
+            }
+
+            let pivot = lu.data[col * n + col];
+            if pivot.abs() <= tolerance * row_sums[permutation[col]].min(column_sums[col]) {
+                singular = true;
+            }
+            if pivot == 0.0 {
+                // The largest entry is zero, so the column below is already eliminated
+                continue;
+            }
+
+            for row in col + 1..n {
+                let factor = lu.data[row * n + col] / pivot;
+                lu.data[row * n + col] = factor;
+                for c in col + 1..n {
+                    lu.data[row * n + c] -= factor * lu.data[col * n + c];
+                }
+            }
+        }
+
+        Ok(LuFactors {
+            lu,
+            permutation,
+            swaps,
+            singular,
+        })
+    }
+}
@@ -1,8 +1,21 @@
 pub mod matrix;
+pub mod accessors;
+pub mod accumulator;
 pub mod constructors;
+pub mod decompositions;
+pub mod elimination;
+#[cfg(feature = "pgm")]
+pub mod export;
 pub mod helpers;
 pub mod operations;
 pub mod overload;
+pub mod predicates;
+pub mod quaternion;
+pub mod serialize;
+pub mod statistics;
 pub mod transforms;
 
-pub use self::matrix::Matrix;
\ No newline at end of file
+pub use self::accumulator::VectorAccumulator;
+pub use self::matrix::Matrix;
+pub use self::quaternion::Quaternion;
+pub use self::transforms::{Padding, normalize_angle, normalize_angle_signed};
\ No newline at end of file
@@ -1,5 +1,11 @@
 use crate::core::Matrix;
 
+/// Upper bound on Taylor terms in `exp_series`; the scaled matrix has norm at most 1/2
+const MAX_EXP_TERMS: usize = 30;
+
+/// Upper bound on squarings in `exp_series`; finite norms never need more than ~1025
+const MAX_EXP_SQUARINGS: i32 = 1100;
+
 impl Matrix {
     /// Applies a function to each element of the matrix, returning a new matrix
     ///
@@ -24,6 +30,159 @@ impl Matrix {
         }
     }
 
+    /// Applies one of two functions to each element depending on a predicate
+    ///
+    /// Expresses piecewise transforms such as leaky ReLU or hard thresholding in a
+    /// single pass, without building an intermediate mask.
+    ///
+    /// # Arguments
+    /// * `pred` - Chooses the branch for each element
+    /// * `if_true` - Applied to elements where `pred` holds
+    /// * `if_false` - Applied to all other elements
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![-2.0, 3.0], rows: 1, columns: 2 };
+    /// let relu = m.apply_conditional(|x| x > 0.0, |x| x, |_| 0.0);
+    /// assert_eq!(relu.data, vec![0.0, 3.0]);
+    /// ```
+    pub fn apply_conditional<P, T, E>(&self, pred: P, if_true: T, if_false: E) -> Self
+    where
+        P: Fn(f64) -> bool,
+        T: Fn(f64) -> f64,
+        E: Fn(f64) -> f64,
+    {
+        Matrix {
+            data: self
+                .data
+                .iter()
+                .map(|&x| if pred(x) { if_true(x) } else { if_false(x) })
+                .collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+
+    /// Computes the natural logarithm of each element
+    ///
+    /// Non-positive elements give `NaN` or `-inf`, as with `f64::ln`.
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, std::f64::consts::E], rows: 1, columns: 2 };
+    /// assert_eq!(m.ln().data, vec![0.0, 1.0]);
+    /// ```
+    pub fn ln(&self) -> Self {
+        self.clone().apply(f64::ln)
+    }
+
+    /// Computes `e^x` for each element
+    ///
+    /// This is not the matrix exponential; see [`Matrix::exp`] for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::zeros(1, 2);
+    /// assert_eq!(m.exp_elementwise().data, vec![1.0, 1.0]);
+    /// ```
+    pub fn exp_elementwise(&self) -> Self {
+        self.clone().apply(f64::exp)
+    }
+
+    /// Computes the square root of each element
+    ///
+    /// Negative elements give `NaN`, as with `f64::sqrt`.
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![4.0, 9.0], rows: 1, columns: 2 };
+    /// assert_eq!(m.sqrt().data, vec![2.0, 3.0]);
+    /// ```
+    pub fn sqrt(&self) -> Self {
+        self.clone().apply(f64::sqrt)
+    }
+
+    /// Computes the absolute value of each element
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![-1.5, 2.0], rows: 1, columns: 2 };
+    /// assert_eq!(m.abs().data, vec![1.5, 2.0]);
+    /// ```
+    pub fn abs(&self) -> Self {
+        self.clone().apply(f64::abs)
+    }
+
+    /// Rounds each element down to an integer
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![-1.5, 2.7], rows: 1, columns: 2 };
+    /// assert_eq!(m.floor().data, vec![-2.0, 2.0]);
+    /// ```
+    pub fn floor(&self) -> Self {
+        self.clone().apply(f64::floor)
+    }
+
+    /// Rounds each element up to an integer
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![-1.5, 2.2], rows: 1, columns: 2 };
+    /// assert_eq!(m.ceil().data, vec![-1.0, 3.0]);
+    /// ```
+    pub fn ceil(&self) -> Self {
+        self.clone().apply(f64::ceil)
+    }
+
+    /// Rounds each element to the nearest integer
+    ///
+    /// Halfway cases round away from zero, as with `f64::round`.
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![-1.5, 2.5, 0.4], rows: 1, columns: 3 };
+    /// assert_eq!(m.round().data, vec![-2.0, 3.0, 0.0]);
+    /// ```
+    pub fn round(&self) -> Self {
+        self.clone().apply(f64::round)
+    }
+
+    /// Replaces non-finite elements with the given substitutes
+    ///
+    /// # Arguments
+    /// * `nan` - Value used in place of `NaN`
+    /// * `posinf` - Value used in place of `+inf`
+    /// * `neginf` - Value used in place of `-inf`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.nan_to_num(0.0, 1e300, -1e300).data, vec![0.0, 1e300, -1e300, 1.0]);
+    /// ```
+    pub fn nan_to_num(&self, nan: f64, posinf: f64, neginf: f64) -> Self {
+        self.clone().apply(|x| {
+            if x.is_nan() {
+                nan
+            } else if x == f64::INFINITY {
+                posinf
+            } else if x == f64::NEG_INFINITY {
+                neginf
+            } else {
+                x
+            }
+        })
+    }
+
     /// Performs matrix addition
     ///
     /// # Arguments
@@ -66,6 +225,70 @@ impl Matrix {
         self.elementwise_operation(other, |a, b| a - b)
     }
 
+    /// Subtracts a row vector from every row of the matrix
+    ///
+    /// # Arguments
+    /// * `row` - Vector (1xN or Nx1) with one entry per column
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` with `row` subtracted from each row
+    /// - `Err` if `row` isn't a vector or its length doesn't match the column count
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// let mean = Matrix { data: vec![2.0, 3.0], rows: 1, columns: 2 };
+    /// let centered = m.subtract_row_vector(&mean).unwrap();
+    /// assert_eq!(centered.data, vec![-1.0, -1.0, 1.0, 1.0]);
+    /// ```
+    pub fn subtract_row_vector(&self, row: &Matrix) -> Result<Self, String> {
+        if !row.check_vector() || row.data.len() != self.columns {
+            return Err(format!(
+                "Expected a vector of length {} to subtract from each row, got {}x{}",
+                self.columns, row.rows, row.columns
+            ));
+        }
+
+        let mut result = self.clone();
+        for (index, value) in result.data.iter_mut().enumerate() {
+            *value -= row.data[index % self.columns];
+        }
+        Ok(result)
+    }
+
+    /// Subtracts a column vector from every column of the matrix
+    ///
+    /// # Arguments
+    /// * `column` - Vector (Nx1 or 1xN) with one entry per row
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` with `column` subtracted from each column
+    /// - `Err` if `column` isn't a vector or its length doesn't match the row count
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 3.0, 2.0, 6.0], rows: 2, columns: 2 };
+    /// let mean = Matrix { data: vec![2.0, 4.0], rows: 2, columns: 1 };
+    /// let centered = m.subtract_column_vector(&mean).unwrap();
+    /// assert_eq!(centered.data, vec![-1.0, 1.0, -2.0, 2.0]);
+    /// ```
+    pub fn subtract_column_vector(&self, column: &Matrix) -> Result<Self, String> {
+        if !column.check_vector() || column.data.len() != self.rows {
+            return Err(format!(
+                "Expected a vector of length {} to subtract from each column, got {}x{}",
+                self.rows, column.rows, column.columns
+            ));
+        }
+
+        let mut result = self.clone();
+        for (index, value) in result.data.iter_mut().enumerate() {
+            *value -= column.data[index / self.columns];
+        }
+        Ok(result)
+    }
+
     /// Performs element-wise (Hadamard) multiplication
     ///
     /// # Arguments
@@ -100,7 +323,7 @@ impl Matrix {
     /// ```
     /// use nelab::Matrix;
     /// let a = Matrix::diagonal(&[4.0, 9.0]);
-    /// let b = Matrix::diagonal(&[2.0, 3.0]);
+    /// let b = Matrix { data: vec![2.0, 1.0, 1.0, 3.0], rows: 2, columns: 2 };
     /// let quotient = a.hadamard_division(&b).unwrap();
     /// assert_eq!(quotient.data, vec![2.0, 0.0, 0.0, 3.0]);
     /// ```
@@ -108,6 +331,38 @@ impl Matrix {
         self.elementwise_operation(other, |a, b| a / b)
     }
 
+    /// Performs element-wise (Hadamard) division, rejecting zero divisors
+    ///
+    /// Unlike `hadamard_division`, which yields `inf`/`NaN` where `other` is zero,
+    /// this reports the division by zero as an error.
+    ///
+    /// # Arguments
+    /// * `other` - Matrix to divide by
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` if dimensions match and `other` has no zero elements
+    /// - `Err` if dimensions mismatch or any element of `other` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix::ones(2, 2);
+    /// assert!(a.checked_hadamard_division(&Matrix::identity(2)).is_err());
+    /// assert_eq!(a.checked_hadamard_division(&a).unwrap(), a);
+    /// ```
+    pub fn checked_hadamard_division(&self, other: &Matrix) -> Result<Self, String> {
+        self.check_dimensions_match(other)?;
+
+        if let Some(index) = other.data.iter().position(|&x| x == 0.0) {
+            return Err(format!(
+                "Division by zero at element ({}, {})",
+                index / other.columns, index % other.columns
+            ));
+        }
+
+        self.hadamard_division(other)
+    }
+
     /// Adds a scalar to each element of the matrix
     ///
     /// # Arguments
@@ -172,6 +427,57 @@ impl Matrix {
         self.scalar_operation(scalar, |a, b| a / b)
     }
 
+    /// Divides each element of the matrix by a scalar, rejecting zero
+    ///
+    /// Unlike `scalar_division`, which yields `inf`/`NaN` for a zero divisor,
+    /// this reports the division by zero as an error.
+    ///
+    /// # Arguments
+    /// * `scalar` - Value to divide by
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` if `scalar` is non-zero
+    /// - `Err` if `scalar == 0.0`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[2.0, 4.0]);
+    /// assert_eq!(m.checked_scalar_division(2.0).unwrap().data, vec![1.0, 0.0, 0.0, 2.0]);
+    /// assert!(m.checked_scalar_division(0.0).is_err());
+    /// ```
+    pub fn checked_scalar_division(&self, scalar: f64) -> Result<Self, String> {
+        if scalar == 0.0 {
+            return Err("Cannot divide matrix by zero".to_string());
+        }
+        Ok(self.scalar_division(scalar))
+    }
+
+    /// Adds a scalar to the diagonal, computing `A + value * I`
+    ///
+    /// # Arguments
+    /// * `value` - Amount added to each diagonal element
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` with the shifted diagonal if matrix is square
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::ones(2, 2);
+    /// assert_eq!(m.diag_add(2.0).unwrap().data, vec![3.0, 1.0, 1.0, 3.0]);
+    /// ```
+    pub fn diag_add(&self, value: f64) -> Result<Self, String> {
+        self.check_square()?;
+
+        let mut result = self.clone();
+        for i in 0..self.rows {
+            result.data[i * self.columns + i] += value;
+        }
+        Ok(result)
+    }
+
     /// Computes the dot product of two vectors
     ///
     /// # Arguments
@@ -184,8 +490,8 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let a = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
-    /// let b = Matrix::new(3, 1, vec![4.0, 5.0, 6.0]);
+    /// let a = Matrix { data: vec![1.0, 2.0, 3.0], rows: 3, columns: 1 };
+    /// let b = Matrix { data: vec![4.0, 5.0, 6.0], rows: 3, columns: 1 };
     /// let dot = a.dot_product(&b).unwrap();
     /// assert_eq!(dot, 32.0);
     /// ```
@@ -203,6 +509,111 @@ impl Matrix {
             .sum())
     }
 
+    /// Computes the outer product `a * b^T` of two vectors
+    ///
+    /// # Arguments
+    /// * `other` - Vector whose entries index the columns of the result
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of size `self.len() x other.len()` with entries `a_i * b_j`
+    /// - `Err` if either input isn't a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 };
+    /// let b = Matrix { data: vec![3.0, 4.0, 5.0], rows: 3, columns: 1 };
+    /// let outer = a.outer_product(&b).unwrap();
+    /// assert_eq!((outer.rows, outer.columns), (2, 3));
+    /// assert_eq!(outer.data, vec![3.0, 4.0, 5.0, 6.0, 8.0, 10.0]);
+    /// ```
+    pub fn outer_product(&self, other: &Matrix) -> Result<Self, String> {
+        if !self.check_vector() || !other.check_vector() {
+            return Err("Both matrices must be vectors (1xN or Nx1)".to_string());
+        }
+
+        let data = self
+            .data
+            .iter()
+            .flat_map(|&a| other.data.iter().map(move |&b| a * b))
+            .collect();
+
+        Ok(Matrix {
+            data,
+            rows: self.data.len(),
+            columns: other.data.len(),
+        })
+    }
+
+    /// Broadcasts a column and a row vector into their pairwise sums
+    ///
+    /// The additive analog of [`Matrix::outer_product`]: entry `(i, j)` is `col[i] + row[j]`.
+    ///
+    /// # Arguments
+    /// * `col` - Column vector (Nx1) indexing the rows of the result
+    /// * `row` - Row vector (1xM) indexing the columns of the result
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of size `N x M`
+    /// - `Err` if `col` isn't Nx1 or `row` isn't 1xM
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let col = Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 };
+    /// let row = Matrix { data: vec![10.0, 20.0], rows: 1, columns: 2 };
+    /// assert_eq!(Matrix::outer_sum(&col, &row).unwrap().data, vec![11.0, 21.0, 12.0, 22.0]);
+    /// ```
+    pub fn outer_sum(col: &Matrix, row: &Matrix) -> Result<Self, String> {
+        if col.columns != 1 {
+            return Err(format!("Expected a column vector (Nx1), got {}x{}", col.rows, col.columns));
+        }
+        if row.rows != 1 {
+            return Err(format!("Expected a row vector (1xM), got {}x{}", row.rows, row.columns));
+        }
+
+        let data = col
+            .data
+            .iter()
+            .flat_map(|&a| row.data.iter().map(move |&b| a + b))
+            .collect();
+
+        Ok(Matrix {
+            data,
+            rows: col.rows,
+            columns: row.columns,
+        })
+    }
+
+    /// Computes the Frobenius inner product `sum(A_ij * B_ij)` of two matrices
+    ///
+    /// This equals `tr(A^T B)` but is computed directly, without forming the product.
+    ///
+    /// # Arguments
+    /// * `other` - Matrix to compute the inner product with
+    ///
+    /// # Returns
+    /// - `Ok(f64)` if dimensions match
+    /// - `Err` if dimensions mismatch
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// let b = Matrix::identity(2);
+    /// assert_eq!(a.frobenius_inner(&b).unwrap(), 5.0);
+    /// ```
+    pub fn frobenius_inner(&self, other: &Matrix) -> Result<f64, String> {
+        self.check_dimensions_match(other)?;
+
+        Ok(self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(&a, &b)| a * b)
+            .sum())
+    }
+
     /// Computes the cross product of two 3D vectors
     ///
     /// # Arguments
@@ -215,8 +626,8 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let a = Matrix::new(3, 1, vec![1.0, 0.0, 0.0]);
-    /// let b = Matrix::new(3, 1, vec![0.0, 1.0, 0.0]);
+    /// let a = Matrix { data: vec![1.0, 0.0, 0.0], rows: 3, columns: 1 };
+    /// let b = Matrix { data: vec![0.0, 1.0, 0.0], rows: 3, columns: 1 };
     /// let cross = a.cross_product(&b).unwrap();
     /// assert_eq!(cross.data, vec![0.0, 0.0, 1.0]);
     /// ```
@@ -250,7 +661,7 @@ impl Matrix {
     /// ```
     /// use nelab::Matrix;
     /// use std::f64::consts::SQRT_2;
-    /// let v = Matrix::new(2, 1, vec![1.0, 1.0]);
+    /// let v = Matrix { data: vec![1.0, 1.0], rows: 2, columns: 1 };
     /// let mag = v.magnitude().unwrap();
     /// assert!((mag - SQRT_2).abs() < 1e-10);
     /// ```
@@ -262,46 +673,188 @@ impl Matrix {
         self.dot_product(self).map(|dot| dot.sqrt())
     }
 
-    /// Computes the unit vector in the same direction
+    /// Computes the magnitude of a vector without intermediate overflow
+    ///
+    /// Components are scaled by the largest absolute component before squaring,
+    /// so vectors with huge (or tiny) entries still yield a finite, accurate length.
     ///
     /// # Returns
-    /// - `Ok(Matrix)` containing unit vector if matrix is a non-zero vector
-    /// - `Err` if matrix is not a vector or has zero magnitude
+    /// - `Ok(f64)` containing the magnitude if matrix is a vector
+    /// - `Err` if matrix is not a vector
     ///
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let v = Matrix::new(2, 1, vec![3.0, 4.0]);
-    /// let unit = v.unit_vector().unwrap();
-    /// assert!((unit.magnitude().unwrap() - 1.0).abs() < 1e-10);
+    /// let v = Matrix { data: vec![3e200, 4e200], rows: 2, columns: 1 };
+    /// assert!(v.magnitude().unwrap().is_infinite());
+    /// assert!((v.magnitude_stable().unwrap() / 5e200 - 1.0).abs() < 1e-12);
     /// ```
-    pub fn unit_vector(&self) -> Result<Self, String> {
-        let mag = self.magnitude()?;
+    pub fn magnitude_stable(&self) -> Result<f64, String> {
+        if !self.check_vector() {
+            return Err("Matrix must be a vector to calculate magnitude".to_string());
+        }
 
-        if mag == 0.0 {
-            return Err("Cannot normalize zero-length vector".to_string());
+        let scale = self.data.iter().fold(0.0_f64, |max, &x| max.max(x.abs()));
+        if scale == 0.0 || !scale.is_finite() {
+            return Ok(scale);
         }
 
-        Ok(self.scalar_operation(mag, |a, m| a / m))
+        let sum: f64 = self.data.iter().map(|&x| (x / scale) * (x / scale)).sum();
+        Ok(scale * sum.sqrt())
     }
 
-    /// Normalizes the vector in-place to unit length
+    /// Computes the Frobenius norm `sqrt(Σ a_ij²)`
+    ///
+    /// Works for any shape; for a vector this is its Euclidean length.
     ///
     /// # Returns
-    /// - `Ok(())` if successful
-    /// - `Err` if matrix is not a vector or has zero magnitude
+    /// The Frobenius norm, or 0 for a matrix without elements
     ///
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let mut v = Matrix::new(2, 1, vec![3.0, 4.0]);
-    /// v.normalize().unwrap();
-    /// assert!((v.magnitude().unwrap() - 1.0).abs() < 1e-10);
+    /// let m = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.frobenius_norm(), 5.0);
     /// ```
-    pub fn normalize(&mut self) -> Result<(), String> {
-        let mag = self.magnitude()?;
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data.iter().map(|x| x * x).sum::<f64>().sqrt()
+    }
 
-        if mag == 0.0 {
+    /// Computes the induced 1-norm (maximum absolute column sum)
+    ///
+    /// # Returns
+    /// `max_j Σ_i |a_ij|`, or 0 for a matrix without elements
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, -2.0, 3.0, 0.5], rows: 2, columns: 2 };
+    /// assert_eq!(m.norm_1(), 4.0);
+    /// ```
+    pub fn norm_1(&self) -> f64 {
+        (0..self.columns)
+            .map(|col| (0..self.rows).map(|row| self.data[row * self.columns + col].abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// Computes the induced infinity norm (maximum absolute row sum)
+    ///
+    /// # Returns
+    /// `max_i Σ_j |a_ij|`, or 0 for a matrix without elements
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, -2.0, 3.0, 0.5], rows: 2, columns: 2 };
+    /// assert_eq!(m.norm_inf(), 3.5);
+    /// ```
+    pub fn norm_inf(&self) -> f64 {
+        if self.columns == 0 {
+            return 0.0;
+        }
+
+        self.data
+            .chunks(self.columns)
+            .map(|row| row.iter().map(|x| x.abs()).sum::<f64>())
+            .fold(0.0, f64::max)
+    }
+
+    /// Scales the matrix down so its Frobenius norm is at most `max_norm`
+    ///
+    /// This is the usual gradient-clipping step: the direction is preserved and only
+    /// the overall size is limited. Matrices already within the limit (including the
+    /// zero matrix) are returned unchanged.
+    ///
+    /// # Arguments
+    /// * `max_norm` - Largest allowed Frobenius norm (negative values are treated as zero)
+    ///
+    /// # Returns
+    /// Matrix with norm `min(‖A‖_F, max_norm)`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let grad = Matrix { data: vec![6.0, 8.0], rows: 1, columns: 2 };
+    /// let clipped = grad.clip_by_frobenius_norm(5.0);
+    /// assert_eq!(clipped.data, vec![3.0, 4.0]);
+    /// ```
+    pub fn clip_by_frobenius_norm(&self, max_norm: f64) -> Self {
+        let max_norm = max_norm.max(0.0);
+        let norm = self.frobenius_norm();
+
+        if norm <= max_norm {
+            return self.clone();
+        }
+
+        self.scalar_multiplication(max_norm / norm)
+    }
+
+    /// Computes the unit vector in the same direction
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing unit vector if matrix is a non-zero vector
+    /// - `Err` if matrix is not a vector or has zero magnitude
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let v = Matrix { data: vec![3.0, 4.0], rows: 2, columns: 1 };
+    /// let unit = v.unit_vector().unwrap();
+    /// assert!((unit.magnitude().unwrap() - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn unit_vector(&self) -> Result<Self, String> {
+        let mag = self.magnitude()?;
+
+        if mag == 0.0 {
+            return Err("Cannot normalize zero-length vector".to_string());
+        }
+
+        Ok(self.scalar_operation(mag, |a, m| a / m))
+    }
+
+    /// Computes the unit vector, falling back to zero for (near-)zero vectors
+    ///
+    /// Unlike [`Matrix::unit_vector`], a vector whose magnitude is at most
+    /// `f64::EPSILON` yields a zero vector instead of an error, so direction
+    /// computations never have to special-case degenerate input.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the unit vector, or zeros of the same shape
+    /// - `Err` if matrix is not a vector
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let tiny = Matrix { data: vec![1e-20, 0.0], rows: 2, columns: 1 };
+    /// assert_eq!(tiny.normalize_or_zero().unwrap(), Matrix::zeros(2, 1));
+    /// ```
+    pub fn normalize_or_zero(&self) -> Result<Self, String> {
+        let mag = self.magnitude()?;
+
+        if mag <= f64::EPSILON {
+            return Ok(Matrix::zeros(self.rows, self.columns));
+        }
+
+        Ok(self.scalar_operation(mag, |a, m| a / m))
+    }
+
+    /// Normalizes the vector in-place to unit length
+    ///
+    /// # Returns
+    /// - `Ok(())` if successful
+    /// - `Err` if matrix is not a vector or has zero magnitude
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let mut v = Matrix { data: vec![3.0, 4.0], rows: 2, columns: 1 };
+    /// v.normalize().unwrap();
+    /// assert!((v.magnitude().unwrap() - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn normalize(&mut self) -> Result<(), String> {
+        let mag = self.magnitude()?;
+
+        if mag == 0.0 {
             return Err("Cannot normalize zero-length vector".to_string());
         }
 
@@ -324,8 +877,8 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let a = Matrix::new(2, 1, vec![1.0, 0.0]);
-    /// let b = Matrix::new(2, 1, vec![1.0, 1.0]);
+    /// let a = Matrix { data: vec![1.0, 0.0], rows: 2, columns: 1 };
+    /// let b = Matrix { data: vec![1.0, 1.0], rows: 2, columns: 1 };
     /// let proj = a.scalar_projection_onto(&b).unwrap();
     /// assert!((proj - 1.0/f64::sqrt(2.0)).abs() < 1e-10);
     /// ```
@@ -347,8 +900,8 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let a = Matrix::new(2, 1, vec![1.0, 0.0]);
-    /// let b = Matrix::new(2, 1, vec![1.0, 1.0]);
+    /// let a = Matrix { data: vec![1.0, 0.0], rows: 2, columns: 1 };
+    /// let b = Matrix { data: vec![1.0, 1.0], rows: 2, columns: 1 };
     /// let proj = a.vector_projection_onto(&b).unwrap();
     /// assert_eq!(proj.data, vec![0.5, 0.5]);
     /// ```
@@ -367,7 +920,7 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let m = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], rows: 2, columns: 3 };
     /// let transposed = m.transpose();
     /// assert_eq!(transposed.data, vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
     /// assert_eq!(transposed.rows, 3);
@@ -389,6 +942,31 @@ impl Matrix {
         }
     }
 
+    /// Transposes a square matrix in-place without allocating
+    ///
+    /// # Returns
+    /// - `Ok(())` if successful
+    /// - `Err` if matrix isn't square (its shape would have to change)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let mut m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// m.transpose_in_place().unwrap();
+    /// assert_eq!(m.data, vec![1.0, 3.0, 2.0, 4.0]);
+    /// ```
+    pub fn transpose_in_place(&mut self) -> Result<(), String> {
+        self.check_square()?;
+
+        for i in 0..self.rows {
+            for j in i + 1..self.columns {
+                self.data.swap(i * self.columns + j, j * self.columns + i);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Performs standard matrix multiplication
     ///
     /// # Arguments
@@ -401,8 +979,8 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
-    /// let b = Matrix::new(3, 2, vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0]);
+    /// let a = Matrix { data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], rows: 2, columns: 3 };
+    /// let b = Matrix { data: vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0], rows: 3, columns: 2 };
     /// let product = a.multiply(&b).unwrap();
     /// assert_eq!(product.data, vec![58.0, 64.0, 139.0, 154.0]);
     /// ```
@@ -428,49 +1006,130 @@ impl Matrix {
         })
     }
 
-    /// Computes the minor matrix (used for determinant calculation)
+    /// Applies the congruence transformation `P^T * A * P`
+    ///
+    /// Changes the basis of the quadratic form `x^T A x` to `y^T (P^T A P) y`
+    /// with `x = P y`. Symmetry of `A` is preserved.
     ///
     /// # Arguments
-    /// * `row` - Row to exclude (0-based)
-    /// * `col` - Column to exclude (0-based)
+    /// * `p` - Change of basis matrix with as many rows as `A`
     ///
     /// # Returns
-    /// - `Ok(Matrix)` containing the minor if indices are valid
-    /// - `Err` if matrix isn't square or indices are out of bounds
-    fn minor(&self, row: usize, col: usize) -> Result<Self, String> {
+    /// - `Ok(Matrix)` containing `P^T A P`, of size `p.columns x p.columns`
+    /// - `Err` if matrix isn't square or `p` has the wrong number of rows
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix::diagonal(&[1.0, 2.0]);
+    /// let p = Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 };
+    /// assert_eq!(a.congruence_transform(&p).unwrap().data, vec![9.0]);
+    /// ```
+    pub fn congruence_transform(&self, p: &Matrix) -> Result<Self, String> {
         self.check_square()?;
-        
-        if row >= self.rows || col >= self.columns {
+        if p.rows != self.rows {
             return Err(format!(
-                "Row {} or column {} out of bounds for {}x{} matrix",
-                row, col, self.rows, self.columns
+                "Basis matrix has {} rows, expected {} to match the {}x{} matrix",
+                p.rows, self.rows, self.rows, self.columns
             ));
         }
 
-        let size = self.rows - 1;
-        let mut data = Vec::with_capacity(size * size);
+        p.transpose().multiply(&self.multiply(p)?)
+    }
+
+    /// Multiplies a sequence of matrices using the cheapest parenthesization
+    ///
+    /// The evaluation order is chosen by dynamic programming over the chain's
+    /// dimensions to minimize the number of scalar multiplications.
+    ///
+    /// # Arguments
+    /// * `matrices` - Matrices to multiply, left to right
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the product if the chain is non-empty and compatible
+    /// - `Err` if the chain is empty or adjacent dimensions are incompatible
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix::ones(2, 3);
+    /// let b = Matrix::ones(3, 4);
+    /// let c = Matrix::ones(4, 1);
+    /// let product = Matrix::multiply_chain(&[a, b, c]).unwrap();
+    /// assert_eq!(product.data, vec![12.0, 12.0]);
+    /// ```
+    pub fn multiply_chain(matrices: &[Matrix]) -> Result<Self, String> {
+        let split = Self::chain_order(matrices)?;
+        Self::multiply_chain_range(matrices, &split, 0, matrices.len() - 1)
+    }
+
+    /// Solves the matrix-chain ordering problem
+    ///
+    /// # Returns
+    /// - `Ok(split)` where `split[i][j]` is the index after which the sub-chain
+    ///   `i..=j` is best divided
+    /// - `Err` if the chain is empty or adjacent dimensions are incompatible
+    fn chain_order(matrices: &[Matrix]) -> Result<Vec<Vec<usize>>, String> {
+        if matrices.is_empty() {
+            return Err("Cannot multiply an empty chain of matrices".to_string());
+        }
+
+        for (i, pair) in matrices.windows(2).enumerate() {
+            pair[0]
+                .check_multiplication_compatible(&pair[1])
+                .map_err(|e| format!("Chain positions {} and {}: {}", i, i + 1, e))?;
+        }
 
-        for i in 0..self.rows {
-            if i == row {
-                continue;
-            }
-            for j in 0..self.columns {
-                if j == col {
-                    continue;
+        // Dimensions of matrix i are dims[i] x dims[i + 1]
+        let n = matrices.len();
+        let mut dims: Vec<usize> = matrices.iter().map(|m| m.rows).collect();
+        dims.push(matrices[n - 1].columns);
+
+        let mut cost = vec![vec![0usize; n]; n];
+        let mut split = vec![vec![0usize; n]; n];
+
+        for length in 2..=n {
+            for i in 0..=n - length {
+                let j = i + length - 1;
+                cost[i][j] = usize::MAX;
+                for k in i..j {
+                    let candidate = cost[i][k] + cost[k + 1][j] + dims[i] * dims[k + 1] * dims[j + 1];
+                    if candidate < cost[i][j] {
+                        cost[i][j] = candidate;
+                        split[i][j] = k;
+                    }
                 }
-                data.push(self.data[i * self.columns + j]);
             }
         }
 
-        Ok(Matrix {
-            data,
-            rows: size,
-            columns: size,
-        })
+        Ok(split)
+    }
+
+    /// Multiplies the sub-chain `first..=last` following the precomputed splits
+    fn multiply_chain_range(
+        matrices: &[Matrix],
+        split: &[Vec<usize>],
+        first: usize,
+        last: usize,
+    ) -> Result<Self, String> {
+        if first == last {
+            return Ok(matrices[first].clone());
+        }
+
+        let k = split[first][last];
+        let left = Self::multiply_chain_range(matrices, split, first, k)?;
+        let right = Self::multiply_chain_range(matrices, split, k + 1, last)?;
+        left.multiply(&right)
     }
 
     /// Computes the determinant of the matrix
     ///
+    /// 1x1 and 2x2 matrices are evaluated directly; larger ones use LU with partial
+    /// pivoting, taking the product of U's diagonal times `(-1)^swaps` in O(n³).
+    /// A pivot no larger than `n * f64::EPSILON` times the smaller absolute sum of
+    /// its original row and column is rounding residue of an exactly singular
+    /// matrix, so the determinant is then reported as `0.0`.
+    ///
     /// # Returns
     /// - `Ok(f64)` containing the determinant if matrix is square
     /// - `Err` if matrix isn't square
@@ -478,7 +1137,7 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let m = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
     /// let det = m.determinant().unwrap();
     /// assert_eq!(det, -2.0);
     /// ```
@@ -488,27 +1147,238 @@ impl Matrix {
         match self.rows {
             1 => Ok(self.data[0]),
             2 => Ok(self.data[0] * self.data[3] - self.data[1] * self.data[2]),
-            3 => {
-                // Rule of Sarrus for 3x3
-                let a = &self.data;
-                Ok(a[0] * a[4] * a[8] + 
-                   a[1] * a[5] * a[6] + 
-                   a[2] * a[3] * a[7] -
-                   a[2] * a[4] * a[6] -
-                   a[1] * a[3] * a[8] -
-                   a[0] * a[5] * a[7])
-            }
             _ => {
-                // Laplace expansion for NxN
-                let mut det = 0.0;
-                for col in 0..self.columns {
-                    let minor = self.minor(0, col)?;
-                    let sign = if col % 2 == 0 { 1.0 } else { -1.0 };
-                    det += self.data[col] * sign * minor.determinant()?;
+                // Product of U's diagonal, negated once per row swap. A pivot that is
+                // round-off relative to its original row and column makes the matrix singular.
+                let factors = self.lu_factorize()?;
+                let n = self.rows;
+                if factors.singular {
+                    return Ok(0.0);
+                }
+
+                let sign = if factors.swaps % 2 == 0 { 1.0 } else { -1.0 };
+                Ok((0..n).fold(sign, |det, i| det * factors.lu.data[i * n + i]))
+            }
+        }
+    }
+
+    /// Computes the orientation of the matrix (the sign of its determinant)
+    ///
+    /// Only the signs of the LU pivots are combined, so the result stays
+    /// correct even when the determinant itself would overflow.
+    ///
+    /// # Returns
+    /// - `Ok(1)` if the determinant is positive
+    /// - `Ok(-1)` if the determinant is negative
+    /// - `Ok(0)` if the matrix is singular
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let reflection = Matrix::diagonal(&[1.0, -1.0]);
+    /// assert_eq!(reflection.orientation().unwrap(), -1);
+    ///
+    /// let huge = Matrix::diagonal(&[1e200, 1e200, 1e200]);
+    /// assert_eq!(huge.orientation().unwrap(), 1);
+    /// ```
+    pub fn orientation(&self) -> Result<i32, String> {
+        let factors = self.lu_factorize()?;
+
+        if factors.singular {
+            return Ok(0);
+        }
+
+        let n = self.rows;
+        let mut sign = if factors.swaps % 2 == 0 { 1 } else { -1 };
+        for i in 0..n {
+            if factors.lu.data[i * n + i] < 0.0 {
+                sign = -sign;
+            }
+        }
+
+        Ok(sign)
+    }
+
+    /// Computes the natural log of the absolute determinant and its sign
+    ///
+    /// Sums `ln|U_ii|` over the LU pivots, so the result stays finite for
+    /// matrices whose determinant would overflow or underflow `f64`.
+    ///
+    /// # Returns
+    /// - `Ok((ln|det|, sign))` with `sign` being `1` or `-1`
+    /// - `Err` if matrix isn't square or is singular
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[1e200, -1e200, 1e200]);
+    /// let (log_det, sign) = m.log_abs_determinant().unwrap();
+    /// assert!((log_det - 600.0 * 10f64.ln()).abs() < 1e-9);
+    /// assert_eq!(sign, -1);
+    /// ```
+    pub fn log_abs_determinant(&self) -> Result<(f64, i32), String> {
+        let factors = self.lu_factorize()?;
+
+        if factors.singular {
+            return Err("Matrix is singular (determinant = 0), log-determinant undefined".to_string());
+        }
+
+        let n = self.rows;
+        let mut sign = if factors.swaps % 2 == 0 { 1 } else { -1 };
+        let mut log_det = 0.0;
+        for i in 0..n {
+            let pivot = factors.lu.data[i * n + i];
+            if pivot < 0.0 {
+                sign = -sign;
+            }
+            log_det += pivot.abs().ln();
+        }
+
+        Ok((log_det, sign))
+    }
+
+    /// Computes the determinant with the fraction-free Bareiss algorithm
+    ///
+    /// Every intermediate value is itself a minor of the input and each division is
+    /// exact, so for integer-valued matrices the result is the exact integer
+    /// determinant as long as the intermediate minors fit in 53 bits. Row swaps are
+    /// only used to avoid zero pivots.
+    ///
+    /// # Returns
+    /// - `Ok(f64)` containing the determinant if matrix is square
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![2.0, 3.0, 1.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.determinant_bareiss().unwrap(), 5.0);
+    /// ```
+    pub fn determinant_bareiss(&self) -> Result<f64, String> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let mut m = self.clone();
+        let mut sign = 1.0;
+        let mut previous = 1.0;
+
+        for k in 0..n.saturating_sub(1) {
+            if m.data[k * n + k] == 0.0 {
+                let Some(pivot_row) = (k + 1..n).find(|&row| m.data[row * n + k] != 0.0) else {
+                    return Ok(0.0);
+                };
+                m.swap_rows(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = m.data[k * n + k];
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    m.data[i * n + j] =
+                        (m.data[i * n + j] * pivot - m.data[i * n + k] * m.data[k * n + j]) / previous;
                 }
-                Ok(det)
             }
+            previous = pivot;
         }
+
+        Ok(if n == 0 { 1.0 } else { sign * m.data[n * n - 1] })
+    }
+
+    /// Computes the cofactor `(-1)^(row + col) * M_row,col`
+    ///
+    /// `M_row,col` is the minor: the determinant of the matrix with `row` and
+    /// `col` removed. The cofactor of a 1x1 matrix is 1.
+    ///
+    /// # Arguments
+    /// * `row` - Row to remove (0-based)
+    /// * `col` - Column to remove (0-based)
+    ///
+    /// # Returns
+    /// - `Ok(f64)` containing the cofactor
+    /// - `Err` if matrix isn't square or an index is out of bounds
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.cofactor(0, 1).unwrap(), -3.0);
+    /// ```
+    pub fn cofactor(&self, row: usize, col: usize) -> Result<f64, String> {
+        self.check_square()?;
+        if row >= self.rows || col >= self.columns {
+            return Err(format!(
+                "Index ({}, {}) out of bounds for {}x{} matrix",
+                row, col, self.rows, self.columns
+            ));
+        }
+
+        let n = self.rows;
+        let minor = Matrix {
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| index / n != row && index % n != col)
+                .map(|(_, &x)| x)
+                .collect(),
+            rows: n - 1,
+            columns: n - 1,
+        };
+
+        let sign = if (row + col).is_multiple_of(2) { 1.0 } else { -1.0 };
+        Ok(sign * minor.determinant()?)
+    }
+
+    /// Computes the adjugate (transposed cofactor matrix)
+    ///
+    /// Satisfies `A * adj(A) = det(A) * I`, also for singular matrices.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the adjugate
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.adjugate().unwrap().data, vec![4.0, -2.0, -3.0, 1.0]);
+    /// ```
+    pub fn adjugate(&self) -> Result<Self, String> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let mut result = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                result.data[j * n + i] = self.cofactor(i, j)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Computes the leading principal minors
+    ///
+    /// Returns the determinants of the top-left `1x1, 2x2, ..., nxn` blocks. A
+    /// symmetric matrix is positive definite exactly when all of them are
+    /// positive (Sylvester's criterion).
+    ///
+    /// # Returns
+    /// - `Ok(Vec<f64>)` containing the `n` minors, smallest block first
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![2.0, 1.0, 1.0, 2.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.leading_principal_minors().unwrap(), vec![2.0, 3.0]);
+    /// ```
+    pub fn leading_principal_minors(&self) -> Result<Vec<f64>, String> {
+        self.check_square()?;
+
+        (1..=self.rows)
+            .map(|k| self.submatrix(0..k, 0..k)?.determinant())
+            .collect()
     }
 
     /// Computes the matrix inverse
@@ -520,7 +1390,7 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let m = Matrix::new(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
+    /// let m = Matrix { data: vec![4.0, 7.0, 2.0, 6.0], rows: 2, columns: 2 };
     /// let inv = m.inverse().unwrap();
     /// // Verify it's the inverse by multiplying
     /// let product = m.multiply(&inv).unwrap();
@@ -528,27 +1398,114 @@ impl Matrix {
     /// assert!((product.data[3] - 1.0).abs() < 1e-10);
     /// ```
     pub fn inverse(&self) -> Result<Self, String> {
+        self.inverse_and_determinant().map(|(inverse, _)| inverse)
+    }
+
+    /// Computes the inverse and the determinant in a single elimination
+    ///
+    /// Runs Gauss-Jordan elimination with partial pivoting on `[A | I]`; the
+    /// determinant falls out as the product of the pivots, negated once per row
+    /// swap, so no separate factorization is needed.
+    ///
+    /// # Returns
+    /// - `Ok((Matrix, f64))` containing the inverse and the determinant
+    /// - `Err` if matrix isn't square or is singular (a pivot of magnitude at most `f64::EPSILON`)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![4.0, 7.0, 2.0, 6.0], rows: 2, columns: 2 };
+    /// let (inv, det) = m.inverse_and_determinant().unwrap();
+    /// assert!((det - 10.0).abs() < 1e-12);
+    /// assert!((inv.data[0] - 0.6).abs() < 1e-12);
+    /// ```
+    pub fn inverse_and_determinant(&self) -> Result<(Self, f64), String> {
         self.check_square()?;
-        let det = self.determinant()?;
-        
-        if det.abs() < f64::EPSILON {
-            return Err("Matrix is singular (determinant = 0), cannot invert".to_string());
-        }
 
-        let size = self.rows;
-        let mut cofactors = Matrix::ones(size, size);
-
-        // Calculate cofactor matrix
-        for row in 0..size {
-            for col in 0..size {
-                let minor = self.minor(row, col)?;
-                let sign = if (row + col) % 2 == 0 { 1.0 } else { -1.0 };
-                cofactors.data[col * size + row] = sign * minor.determinant()?;
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut inverse = Matrix::identity(n);
+        let mut det = 1.0;
+
+        for col in 0..n {
+            // Pick the largest remaining entry in this column as pivot
+            let pivot_row = (col..n)
+                .max_by(|&x, &y| a.data[x * n + col].abs().total_cmp(&a.data[y * n + col].abs()))
+                .unwrap_or(col);
+            if pivot_row != col {
+                a.swap_rows(pivot_row, col);
+                inverse.swap_rows(pivot_row, col);
+                det = -det;
             }
+
+            let pivot = a.data[col * n + col];
+            if pivot.abs() <= f64::EPSILON {
+                return Err("Matrix is singular (determinant = 0), cannot invert".to_string());
+            }
+            det *= pivot;
+
+            for c in 0..n {
+                a.data[col * n + c] /= pivot;
+                inverse.data[col * n + c] /= pivot;
+            }
+
+            for row in (0..n).filter(|&row| row != col) {
+                let factor = a.data[row * n + col];
+                if factor == 0.0 {
+                    continue;
+                }
+                for c in 0..n {
+                    a.data[row * n + c] -= factor * a.data[col * n + c];
+                    inverse.data[row * n + c] -= factor * inverse.data[col * n + c];
+                }
+            }
+        }
+
+        Ok((inverse, det))
+    }
+
+    /// Computes the matrix inverse, rejecting matrices that are singular relative to their scale
+    ///
+    /// `inverse` only rejects matrices with an (absolutely) tiny pivot, so a matrix
+    /// with large entries and a comparatively tiny determinant is happily inverted
+    /// into meaningless values. This variant treats the matrix as singular when
+    /// `|det(A)| < tol * ‖A‖∞^n`, a scale-invariant test. Both sides are compared
+    /// as logarithms, so the bound does not overflow for large matrices. Matrices
+    /// that pass are inverted through the LU solve, which has no absolute pivot
+    /// cutoff, so uniformly tiny matrices are inverted too.
+    ///
+    /// # Arguments
+    /// * `tol` - Relative threshold, e.g. `1e-10`; `0.0` only rejects singular matrices
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the inverse if the matrix passes the test
+    /// - `Err` if matrix isn't square or is (relatively) singular
+    ///
+    /// # Notes
+    /// - `|det(A)| / ‖A‖∞^n` shrinks with the size even for well-conditioned
+    ///   matrices (it is `n^(-n/2)` for some orthogonal ones), so scale `tol` down
+    ///   for large matrices
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1e6, 1e6, 1e6, 1e6 + 1e-4], rows: 2, columns: 2 };
+    /// assert!(m.inverse().is_ok());
+    /// assert!(m.inverse_with_tol(1e-10).is_err());
+    /// ```
+    pub fn inverse_with_tol(&self, tol: f64) -> Result<Self, String> {
+        let (log_det, _) = self.log_abs_determinant()?;
+
+        // ln(tol * ‖A‖∞^n); tol = 0 gives -inf, which nothing falls below
+        let log_bound = tol.ln() + self.rows as f64 * self.norm_inf().ln();
+        if log_det < log_bound {
+            return Err(format!(
+                "Matrix is singular relative to its scale (ln|det| = {:.3} < {:.3}), cannot invert",
+                log_det, log_bound
+            ));
         }
 
-        // Transpose and divide by determinant
-        Ok(cofactors.scalar_operation(det, |x, d| x / d))
+        self.solve(&Matrix::identity(self.rows))
     }
 
     /// Computes the trace of the matrix (sum of diagonal elements)
@@ -560,7 +1517,7 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let m = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
     /// let trace = m.trace().unwrap();
     /// assert_eq!(trace, 5.0);
     /// ```
@@ -571,6 +1528,87 @@ impl Matrix {
             .sum())
     }
 
+    /// Scales a square matrix so its trace equals one
+    ///
+    /// This is the normalization used for density matrices; the sign of the
+    /// elements is preserved, so a negative trace flips them.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing `A / tr(A)`
+    /// - `Err` if matrix isn't square or its trace is (numerically) zero
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[1.0, 3.0]);
+    /// assert_eq!(m.normalize_trace().unwrap().data, vec![0.25, 0.0, 0.0, 0.75]);
+    /// ```
+    pub fn normalize_trace(&self) -> Result<Self, String> {
+        let trace = self.trace()?;
+
+        if trace.abs() <= f64::EPSILON {
+            return Err("Cannot normalize matrix with zero trace".to_string());
+        }
+
+        Ok(self.scalar_division(trace))
+    }
+
+    /// Traces out one subsystem of an operator on a tensor product space
+    ///
+    /// The matrix is treated as acting on `A ⊗ B` with basis index `a * dim_b + b`.
+    /// Keeping `A` gives `ρ_A[a][a'] = Σ_b ρ[(a, b)][(a', b)]`; keeping `B` gives
+    /// `ρ_B[b][b'] = Σ_a ρ[(a, b)][(a, b')]`.
+    ///
+    /// # Arguments
+    /// * `dim_a` - Dimension of the first subsystem
+    /// * `dim_b` - Dimension of the second subsystem
+    /// * `keep` - Subsystem to keep: `0` for `A`, `1` for `B`
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of size `dim_a x dim_a` or `dim_b x dim_b`
+    /// - `Err` if the matrix isn't `dim_a * dim_b` square or `keep` isn't 0 or 1
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::identity(4);
+    /// assert_eq!(m.partial_trace(2, 2, 0).unwrap().data, vec![2.0, 0.0, 0.0, 2.0]);
+    /// ```
+    pub fn partial_trace(&self, dim_a: usize, dim_b: usize, keep: usize) -> Result<Self, String> {
+        self.check_square()?;
+        if dim_a * dim_b != self.rows {
+            return Err(format!(
+                "Subsystem dimensions {}x{} don't match the {}x{} matrix",
+                dim_a, dim_b, self.rows, self.columns
+            ));
+        }
+
+        let n = self.columns;
+        let entry = |a: usize, b: usize, a2: usize, b2: usize| self.data[(a * dim_b + b) * n + a2 * dim_b + b2];
+
+        match keep {
+            0 => {
+                let mut result = Matrix::zeros(dim_a, dim_a);
+                for a in 0..dim_a {
+                    for a2 in 0..dim_a {
+                        result.data[a * dim_a + a2] = (0..dim_b).map(|b| entry(a, b, a2, b)).sum();
+                    }
+                }
+                Ok(result)
+            }
+            1 => {
+                let mut result = Matrix::zeros(dim_b, dim_b);
+                for b in 0..dim_b {
+                    for b2 in 0..dim_b {
+                        result.data[b * dim_b + b2] = (0..dim_a).map(|a| entry(a, b, a, b2)).sum();
+                    }
+                }
+                Ok(result)
+            }
+            _ => Err(format!("Subsystem to keep must be 0 or 1, got {}", keep)),
+        }
+    }
+
     /// Computes the rank of the matrix (number of linearly independent rows/columns)
     ///
     /// # Returns
@@ -579,43 +1617,297 @@ impl Matrix {
     /// # Examples
     /// ```
     /// use nelab::Matrix;
-    /// let m = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0], rows: 2, columns: 3 };
     /// assert_eq!(m.rank(), 1);
     /// ```
     pub fn rank(&self) -> usize {
-        // Convert to row echelon form and count non-zero rows
-        let mut matrix = self.clone();
-        let mut rank = 0;
-        let n = self.rows.min(self.columns);
+        // Count the non-zero rows of the reduced row echelon form
+        let reduced = self.rref();
+        (0..reduced.rows)
+            .filter(|&row| reduced[row].iter().any(|&x| x != 0.0))
+            .count()
+    }
 
-        for col in 0..n {
-            // Find pivot row
-            if let Some(pivot_row) = (rank..self.rows)
-                .find(|&row| (matrix.data[row * self.columns + col]).abs() > f64::EPSILON)
-            {
-                // Swap rows if needed
-                if pivot_row != rank {
-                    matrix.swap_rows(pivot_row, rank);
-                }
+    /// Evaluates a polynomial with the matrix as its argument
+    ///
+    /// Computes `c0*I + c1*A + c2*A^2 + ...` using Horner's method, seeded with
+    /// the leading coefficient so that a degree-`d` polynomial needs `d` matrix
+    /// multiplications. An empty coefficient list is the zero polynomial.
+    ///
+    /// # Arguments
+    /// * `coeffs` - Polynomial coefficients in increasing order of degree
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the polynomial value if matrix is square
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let a = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// // A^2 - I
+    /// let p = a.eval_polynomial(&[-1.0, 0.0, 1.0]).unwrap();
+    /// assert_eq!(p.data, vec![6.0, 10.0, 15.0, 21.0]);
+    /// ```
+    pub fn eval_polynomial(&self, coeffs: &[f64]) -> Result<Self, String> {
+        self.check_square()?;
 
-                // Eliminate this column in other rows
-                for row in (0..self.rows).filter(|&r| r != rank) {
-                    let factor = matrix.data[row * self.columns + col] / 
-                                matrix.data[rank * self.columns + col];
-                    
-                    if factor.abs() > f64::EPSILON {
-                        for c in col..self.columns {
-                            let target_idx = row * self.columns + c;
-                            let source_idx = rank * self.columns + c;
-                            matrix.data[target_idx] -= factor * matrix.data[source_idx];
-                        }
-                    }
+        let size = self.rows;
+        let Some((&leading, rest)) = coeffs.split_last() else {
+            return Ok(Matrix::zeros(size, size));
+        };
+        let mut result = Matrix::identity(size).scalar_multiplication(leading);
+
+        for &coeff in rest.iter().rev() {
+            result = result.multiply(self)?;
+            for i in 0..size {
+                result.data[i * size + i] += coeff;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Raises a square matrix to a non-negative integer power
+    ///
+    /// Uses exponentiation by squaring, so only `O(log n)` multiplications are
+    /// needed. `A^0` is the identity.
+    ///
+    /// # Arguments
+    /// * `n` - Power to raise the matrix to
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing `A^n`
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 1.0, 0.0, 1.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.pow(5).unwrap().data, vec![1.0, 5.0, 0.0, 1.0]);
+    /// assert_eq!(m.pow(0).unwrap(), Matrix::identity(2));
+    /// ```
+    pub fn pow(&self, n: u32) -> Result<Self, String> {
+        self.check_square()?;
+
+        let mut base = self.clone();
+        let mut remaining = n;
+        let mut result = Matrix::identity(self.rows);
+
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result.multiply(&base)?;
+            }
+            remaining >>= 1;
+            if remaining > 0 {
+                base = base.multiply(&base)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Raises a square matrix to a signed integer power
+    ///
+    /// Negative exponents invert first, using `A^-k = (A^-1)^k`, then defer
+    /// to [`Matrix::pow`]; `A^0` is the identity.
+    ///
+    /// # Arguments
+    /// * `exponent` - Power to raise the matrix to
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the power
+    /// - `Err` if matrix isn't square, or is singular and `exponent < 0`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[2.0, 4.0]);
+    /// assert_eq!(m.powi(3).unwrap().data, vec![8.0, 0.0, 0.0, 64.0]);
+    /// assert_eq!(m.powi(-1).unwrap().data, vec![0.5, 0.0, 0.0, 0.25]);
+    /// ```
+    pub fn powi(&self, exponent: i32) -> Result<Self, String> {
+        self.check_square()?;
+
+        if exponent < 0 {
+            self.inverse()?.pow(exponent.unsigned_abs())
+        } else {
+            self.pow(exponent.unsigned_abs())
+        }
+    }
+
+    /// Computes the principal invariants of a square matrix
+    ///
+    /// Returns `[I1, I2, ..., In]` where `I1` is the trace, `I2` the sum of the
+    /// 2x2 principal minors, ..., and `In` the determinant. They are the
+    /// coefficients of the characteristic polynomial
+    /// `det(λI - A) = λ^n - I1 λ^(n-1) + I2 λ^(n-2) - ... + (-1)^n In`
+    /// and are unchanged by similarity transforms. Computed with the
+    /// Faddeev-LeVerrier recurrence.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<f64>)` containing the `n` invariants
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[1.0, 2.0, 3.0]);
+    /// assert_eq!(m.invariants().unwrap(), vec![6.0, 11.0, 6.0]);
+    /// ```
+    pub fn invariants(&self) -> Result<Vec<f64>, String> {
+        self.check_square()?;
+
+        let size = self.rows;
+        let mut invariants = Vec::with_capacity(size);
+        let mut m = Matrix::zeros(size, size);
+        let mut coefficient = 1.0; // c_0 of det(λI - A)
+
+        for k in 1..=size {
+            // M_k = A M_(k-1) + c_(k-1) I
+            m = self.multiply(&m)?;
+            for i in 0..size {
+                m.data[i * size + i] += coefficient;
+            }
+
+            // c_k = -tr(A M_k) / k
+            coefficient = -self.frobenius_inner(&m.transpose())? / k as f64;
+
+            let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+            invariants.push(sign * coefficient);
+        }
+
+        Ok(invariants)
+    }
+
+    /// Computes the spectral moments `tr(A), tr(A^2), ..., tr(A^k)`
+    ///
+    /// These are the power sums of the eigenvalues, obtained here by repeated
+    /// multiplication rather than an eigensolver; with Newton's identities they
+    /// determine the characteristic polynomial.
+    ///
+    /// # Arguments
+    /// * `k` - Highest power to include
+    ///
+    /// # Returns
+    /// - `Ok(Vec<f64>)` with `k` entries, the `i`-th being `tr(A^(i+1))`
+    /// - `Err` if matrix isn't square
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[1.0, 2.0]);
+    /// assert_eq!(m.spectral_moments(3).unwrap(), vec![3.0, 5.0, 9.0]);
+    /// ```
+    pub fn spectral_moments(&self, k: usize) -> Result<Vec<f64>, String> {
+        self.check_square()?;
+
+        let mut moments = Vec::with_capacity(k);
+        let mut power = self.clone();
+        for i in 0..k {
+            if i > 0 {
+                power = power.multiply(self)?;
+            }
+            moments.push(power.trace()?);
+        }
+
+        Ok(moments)
+    }
+
+    /// Computes the matrix exponential `e^A`
+    ///
+    /// When the matrix is diagonalizable with real eigenvalues this evaluates
+    /// `P * diag(e^d_i) * P^-1`, which is as accurate as the eigendecomposition.
+    /// Defective matrices and matrices with complex eigenvalues fall back to
+    /// [`Matrix::exp_series`].
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing `e^A` if matrix is square
+    /// - `Err` if matrix isn't square, has a `NaN` or infinite entry, or `e^A`
+    ///   overflows `f64`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::diagonal(&[0.0, 1.0]);
+    /// let e = m.exp().unwrap();
+    /// assert!((e.data[0] - 1.0).abs() < 1e-12);
+    /// assert!((e.data[3] - std::f64::consts::E).abs() < 1e-12);
+    /// ```
+    pub fn exp(&self) -> Result<Self, String> {
+        self.check_square()?;
+        if !self.all_finite() {
+            return Err("Matrix exponential requires finite entries".to_string());
+        }
+
+        if let Ok((p, d)) = self.diagonalize() {
+            let n = self.rows;
+            let exp_d = Matrix::diagonal(&(0..n).map(|i| d.data[i * n + i].exp()).collect::<Vec<_>>());
+            if let Ok(p_inv) = p.inverse() {
+                // An overflowing e^d_i turns into NaN against P's zeros; let the series decide
+                let result = p.multiply(&exp_d)?.multiply(&p_inv)?;
+                if result.all_finite() {
+                    return Ok(result);
                 }
+            }
+        }
+
+        self.exp_series()
+    }
+
+    /// Computes the matrix exponential with a Taylor series and scaling and squaring
+    ///
+    /// The matrix is scaled by `2^-s` until its infinity norm is at most 1/2, the
+    /// series is summed until the terms stop contributing, and the result is squared
+    /// `s` times. Works for every square matrix, including defective ones.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing `e^A` if matrix is square
+    /// - `Err` if matrix isn't square, has a `NaN` or infinite entry, or `e^A`
+    ///   overflows `f64`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// // Nilpotent: e^N = I + N
+    /// let n = Matrix { data: vec![0.0, 1.0, 0.0, 0.0], rows: 2, columns: 2 };
+    /// assert_eq!(n.exp_series().unwrap().data, vec![1.0, 1.0, 0.0, 1.0]);
+    /// ```
+    pub fn exp_series(&self) -> Result<Self, String> {
+        self.check_square()?;
+        if !self.all_finite() {
+            return Err("Matrix exponential requires finite entries".to_string());
+        }
 
-                rank += 1;
+        let n = self.rows;
+        // ‖A‖∞ itself can overflow, so take its logarithm via the norm of A / n
+        let log_norm = self.scalar_division(n as f64).norm_inf().log2() + (n as f64).log2();
+        let squarings = if log_norm > -1.0 { ((log_norm + 1.0).ceil() as i32).min(MAX_EXP_SQUARINGS) } else { 0 };
+        // 2^squarings can exceed f64::MAX, so divide by it in two finite halves
+        let half = squarings / 2;
+        let scaled = self
+            .scalar_division(2.0_f64.powi(half))
+            .scalar_division(2.0_f64.powi(squarings - half));
+
+        let mut result = Matrix::identity(n);
+        let mut term = Matrix::identity(n);
+        for k in 1..=MAX_EXP_TERMS {
+            term = term.multiply(&scaled)?.scalar_division(k as f64);
+            result = result.addition(&term)?;
+            // Result entries are O(1) after scaling, so absolute EPSILON is negligible
+            if term.data.iter().all(|&x| x.abs() <= f64::EPSILON) {
+                break;
             }
         }
 
-        rank
+        for _ in 0..squarings {
+            result = result.multiply(&result)?;
+        }
+
+        if !result.all_finite() {
+            return Err("Matrix exponential overflows f64".to_string());
+        }
+
+        Ok(result)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,214 @@
+use crate::core::Matrix;
+
+impl Matrix {
+    /// Checks that no element is `NaN` or infinite
+    ///
+    /// Useful as a health check after a chain of computations, e.g. inverting a
+    /// nearly singular matrix.
+    ///
+    /// # Returns
+    /// `true` if every element is finite, `false` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// assert!(Matrix::identity(2).all_finite());
+    /// assert!(!Matrix { data: vec![1.0, f64::NAN], rows: 1, columns: 2 }.all_finite());
+    /// ```
+    pub fn all_finite(&self) -> bool {
+        self.data.iter().all(|x| x.is_finite())
+    }
+
+    /// Checks if every element is zero within a tolerance
+    ///
+    /// # Arguments
+    /// * `tol` - Maximum allowed absolute value of any element
+    ///
+    /// # Returns
+    /// `true` if all elements satisfy `|a_ij| <= tol`, `false` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1e-12, -1e-12, 0.0, 0.0], rows: 2, columns: 2 };
+    /// assert!(m.is_zero(1e-10));
+    /// assert!(!Matrix::ones(2, 2).is_zero(1e-10));
+    /// ```
+    pub fn is_zero(&self, tol: f64) -> bool {
+        self.data.iter().all(|&x| x.abs() <= tol)
+    }
+
+    /// Checks if the matrix is the identity within a tolerance
+    ///
+    /// # Arguments
+    /// * `tol` - Maximum allowed deviation of any element from the identity
+    ///
+    /// # Returns
+    /// `true` for square matrices within `tol` of the identity, `false` otherwise
+    /// (including all non-square matrices)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// assert!(Matrix::identity(3).is_identity(0.0));
+    /// assert!(!Matrix::diagonal(&[1.0, 2.0]).is_identity(1e-10));
+    /// assert!(!Matrix::zeros(2, 3).is_identity(1e-10));
+    /// ```
+    pub fn is_identity(&self, tol: f64) -> bool {
+        if self.check_square().is_err() {
+            return false;
+        }
+
+        self.data.iter().enumerate().all(|(index, &x)| {
+            let expected = if index / self.columns == index % self.columns { 1.0 } else { 0.0 };
+            (x - expected).abs() <= tol
+        })
+    }
+
+    /// Checks if the matrix is symmetric within a tolerance
+    ///
+    /// # Arguments
+    /// * `tol` - Maximum allowed `|a_ij - a_ji|`
+    ///
+    /// # Returns
+    /// `true` for square matrices equal to their transpose within `tol`, `false` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 2.0, 3.0], rows: 2, columns: 2 };
+    /// assert!(m.is_symmetric(0.0));
+    /// assert!(!Matrix::ones(2, 3).is_symmetric(0.0));
+    /// ```
+    pub fn is_symmetric(&self, tol: f64) -> bool {
+        let n = self.rows;
+        self.check_square().is_ok()
+            && (0..n).all(|i| (i + 1..n).all(|j| (self.data[i * n + j] - self.data[j * n + i]).abs() <= tol))
+    }
+
+    /// Checks if the matrix is (row) diagonally dominant
+    ///
+    /// Requires `|a_ii| >= Σ_{j≠i} |a_ij|` in every row, or `>` when `strict`.
+    /// Strict dominance guarantees invertibility and convergence of the Jacobi
+    /// and Gauss-Seidel iterations.
+    ///
+    /// # Arguments
+    /// * `strict` - Whether the diagonal must strictly exceed the off-diagonal sum
+    ///
+    /// # Returns
+    /// `true` for square matrices satisfying the condition in every row, `false` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![2.0, -2.0, 1.0, 3.0], rows: 2, columns: 2 };
+    /// assert!(m.is_diagonally_dominant(false));
+    /// assert!(!m.is_diagonally_dominant(true));
+    /// ```
+    pub fn is_diagonally_dominant(&self, strict: bool) -> bool {
+        // Each Gershgorin disc is centered on a_ii with the off-diagonal sum as radius
+        match self.gershgorin_bounds() {
+            Ok(discs) => discs
+                .iter()
+                .all(|&(center, radius)| if strict { center.abs() > radius } else { center.abs() >= radius }),
+            Err(_) => false,
+        }
+    }
+
+    /// Checks if the matrix is orthogonal (`A^T * A = I`) within a tolerance
+    ///
+    /// # Arguments
+    /// * `tol` - Maximum allowed deviation of any element of `A^T * A` from the identity
+    ///
+    /// # Returns
+    /// `true` for square matrices with orthonormal columns within `tol`, `false` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let rotation = Matrix { data: vec![0.0, -1.0, 1.0, 0.0], rows: 2, columns: 2 };
+    /// assert!(rotation.is_orthogonal(1e-12));
+    /// assert!(!Matrix::diagonal(&[1.0, 2.0]).is_orthogonal(1e-12));
+    /// ```
+    pub fn is_orthogonal(&self, tol: f64) -> bool {
+        self.check_square().is_ok() && self.transpose().verify_inverse(self, tol)
+    }
+
+    /// Checks if the matrix is row-stochastic within a tolerance
+    ///
+    /// Every entry must be non-negative and every row must sum to one, as in the
+    /// transition matrix of a Markov chain.
+    ///
+    /// # Arguments
+    /// * `tol` - Maximum allowed deviation of each row sum from 1
+    ///
+    /// # Returns
+    /// `true` if all entries are `>= 0` and all row sums are within `tol` of 1
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let p = Matrix { data: vec![0.9, 0.1, 0.5, 0.5], rows: 2, columns: 2 };
+    /// assert!(p.is_row_stochastic(1e-12));
+    /// assert!(!p.transpose().is_row_stochastic(1e-12));
+    /// ```
+    pub fn is_row_stochastic(&self, tol: f64) -> bool {
+        if self.data.iter().any(|&x| x < 0.0) {
+            return false;
+        }
+
+        (0..self.rows).all(|row| (self[row].iter().sum::<f64>() - 1.0).abs() <= tol)
+    }
+
+    /// Checks if the matrix is doubly stochastic within a tolerance
+    ///
+    /// Every entry must be non-negative and every row and every column must sum to one.
+    ///
+    /// # Arguments
+    /// * `tol` - Maximum allowed deviation of each row and column sum from 1
+    ///
+    /// # Returns
+    /// `true` if the matrix is row-stochastic and all column sums are within `tol` of 1
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let p = Matrix { data: vec![0.3, 0.7, 0.7, 0.3], rows: 2, columns: 2 };
+    /// assert!(p.is_doubly_stochastic(1e-12));
+    /// ```
+    pub fn is_doubly_stochastic(&self, tol: f64) -> bool {
+        self.is_row_stochastic(tol)
+            && (0..self.columns).all(|col| {
+                let sum: f64 = (0..self.rows).map(|row| self.data[row * self.columns + col]).sum();
+                (sum - 1.0).abs() <= tol
+            })
+    }
+
+    /// Checks that `inv` is an inverse of `self` within a tolerance
+    ///
+    /// Verifies `self * inv ≈ I`. A near-singular matrix can yield an "inverse"
+    /// full of huge, meaningless values without any error being reported, so this
+    /// serves as a cheap sanity check after inversion.
+    ///
+    /// # Arguments
+    /// * `inv` - Candidate inverse
+    /// * `tol` - Maximum allowed deviation of any element of the product from the identity
+    ///
+    /// # Returns
+    /// `true` if the product is the identity within `tol`, `false` otherwise
+    /// (including incompatible or non-square shapes)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![4.0, 7.0, 2.0, 6.0], rows: 2, columns: 2 };
+    /// let inv = m.inverse().unwrap();
+    /// assert!(m.verify_inverse(&inv, 1e-10));
+    /// assert!(!m.verify_inverse(&Matrix::identity(2), 1e-10));
+    /// ```
+    pub fn verify_inverse(&self, inv: &Matrix, tol: f64) -> bool {
+        self.multiply(inv)
+            .map(|product| product.is_identity(tol))
+            .unwrap_or(false)
+    }
+}
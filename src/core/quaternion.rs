@@ -0,0 +1,324 @@
+use crate::core::Matrix;
+
+/// A quaternion `w + xi + yj + zk`, used to represent 3D rotations.
+///
+/// Rotations are represented by unit quaternions; composing them with
+/// `multiply` avoids the gimbal lock of Euler angles.
+///
+/// # Fields
+/// - `w`: Scalar (real) part
+/// - `x`, `y`, `z`: Vector (imaginary) part
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    /// Creates the identity quaternion (no rotation)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Quaternion;
+    /// let q = Quaternion::identity();
+    /// assert_eq!((q.w, q.x, q.y, q.z), (1.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn identity() -> Self {
+        Quaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 }
+    }
+
+    /// Creates a unit quaternion rotating by `angle` around `axis`
+    ///
+    /// # Arguments
+    /// * `axis` - 3D vector giving the rotation axis (need not be unit length)
+    /// * `angle` - Rotation angle in radians
+    ///
+    /// # Returns
+    /// - `Ok(Quaternion)` if the axis is a non-zero 3D vector
+    /// - `Err` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, Quaternion};
+    /// use std::f64::consts::PI;
+    /// let z = Matrix { data: vec![0.0, 0.0, 1.0], rows: 3, columns: 1 };
+    /// let q = Quaternion::from_axis_angle(&z, PI).unwrap();
+    /// assert!(q.w.abs() < 1e-10);
+    /// assert!((q.z - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn from_axis_angle(axis: &Matrix, angle: f64) -> Result<Self, String> {
+        if !axis.check_3d_vector() {
+            return Err("Rotation axis must be a 3D vector (length 3)".to_string());
+        }
+
+        let k = axis.unit_vector()?;
+        let (sin, cos) = (angle / 2.0).sin_cos();
+
+        Ok(Quaternion {
+            w: cos,
+            x: k.data[0] * sin,
+            y: k.data[1] * sin,
+            z: k.data[2] * sin,
+        })
+    }
+
+    /// Creates a unit quaternion from a 3x3 rotation matrix
+    ///
+    /// # Arguments
+    /// * `m` - Proper rotation matrix (orthogonal with determinant 1)
+    ///
+    /// # Returns
+    /// - `Ok(Quaternion)` if `m` is 3x3
+    /// - `Err` otherwise
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, Quaternion};
+    /// let q = Quaternion::from_rotation_matrix(&Matrix::identity(3)).unwrap();
+    /// assert_eq!(q, Quaternion::identity());
+    /// ```
+    pub fn from_rotation_matrix(m: &Matrix) -> Result<Self, String> {
+        if m.rows != 3 || m.columns != 3 {
+            return Err(format!(
+                "Expected 3x3 rotation matrix, got {}x{}",
+                m.rows, m.columns
+            ));
+        }
+
+        let r = |i: usize, j: usize| m.data[i * 3 + j];
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+
+        // Branch on the largest diagonal term to keep the square root well away from zero
+        let q = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quaternion {
+                w: s / 4.0,
+                x: (r(2, 1) - r(1, 2)) / s,
+                y: (r(0, 2) - r(2, 0)) / s,
+                z: (r(1, 0) - r(0, 1)) / s,
+            }
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = (1.0 + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * 2.0;
+            Quaternion {
+                w: (r(2, 1) - r(1, 2)) / s,
+                x: s / 4.0,
+                y: (r(0, 1) + r(1, 0)) / s,
+                z: (r(0, 2) + r(2, 0)) / s,
+            }
+        } else if r(1, 1) > r(2, 2) {
+            let s = (1.0 + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * 2.0;
+            Quaternion {
+                w: (r(0, 2) - r(2, 0)) / s,
+                x: (r(0, 1) + r(1, 0)) / s,
+                y: s / 4.0,
+                z: (r(1, 2) + r(2, 1)) / s,
+            }
+        } else {
+            let s = (1.0 + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * 2.0;
+            Quaternion {
+                w: (r(1, 0) - r(0, 1)) / s,
+                x: (r(0, 2) + r(2, 0)) / s,
+                y: (r(1, 2) + r(2, 1)) / s,
+                z: s / 4.0,
+            }
+        };
+
+        Ok(q)
+    }
+
+    /// Computes the Hamilton product `self * other`
+    ///
+    /// For rotations, the result applies `other` first and then `self`.
+    ///
+    /// # Arguments
+    /// * `other` - Right-hand quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Quaternion;
+    /// let i = Quaternion { w: 0.0, x: 1.0, y: 0.0, z: 0.0 };
+    /// let j = Quaternion { w: 0.0, x: 0.0, y: 1.0, z: 0.0 };
+    /// // i * j = k
+    /// assert_eq!(i.multiply(&j), Quaternion { w: 0.0, x: 0.0, y: 0.0, z: 1.0 });
+    /// ```
+    pub fn multiply(&self, other: &Quaternion) -> Self {
+        Quaternion {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        }
+    }
+
+    /// Computes the conjugate `w - xi - yj - zk` (the inverse rotation for unit quaternions)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Quaternion;
+    /// let q = Quaternion { w: 1.0, x: 2.0, y: 3.0, z: 4.0 };
+    /// assert_eq!(q.conjugate(), Quaternion { w: 1.0, x: -2.0, y: -3.0, z: -4.0 });
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        Quaternion {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    /// Computes the magnitude (norm) of the quaternion
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Quaternion;
+    /// let q = Quaternion { w: 1.0, x: 1.0, y: 1.0, z: 1.0 };
+    /// assert_eq!(q.magnitude(), 2.0);
+    /// ```
+    pub fn magnitude(&self) -> f64 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Normalizes the quaternion in-place to unit length
+    ///
+    /// # Returns
+    /// - `Ok(())` if successful
+    /// - `Err` if the quaternion has zero magnitude
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Quaternion;
+    /// let mut q = Quaternion { w: 2.0, x: 0.0, y: 0.0, z: 0.0 };
+    /// q.normalize().unwrap();
+    /// assert_eq!(q, Quaternion::identity());
+    /// ```
+    pub fn normalize(&mut self) -> Result<(), String> {
+        let mag = self.magnitude();
+
+        if mag == 0.0 {
+            return Err("Cannot normalize zero quaternion".to_string());
+        }
+
+        self.w /= mag;
+        self.x /= mag;
+        self.y /= mag;
+        self.z /= mag;
+
+        Ok(())
+    }
+
+    /// Rotates a 3D vector by this (unit) quaternion, computing `q v q*`
+    ///
+    /// # Arguments
+    /// * `v` - 3D vector to rotate
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the rotated vector, with the same shape as `v`
+    /// - `Err` if `v` isn't a 3D vector
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, Quaternion};
+    /// use std::f64::consts::FRAC_PI_2;
+    /// let z = Matrix { data: vec![0.0, 0.0, 1.0], rows: 3, columns: 1 };
+    /// let q = Quaternion::from_axis_angle(&z, FRAC_PI_2).unwrap();
+    /// let x = Matrix { data: vec![1.0, 0.0, 0.0], rows: 3, columns: 1 };
+    /// let rotated = q.rotate_vector(&x).unwrap();
+    /// assert!((rotated.data[1] - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn rotate_vector(&self, v: &Matrix) -> Result<Matrix, String> {
+        if !v.check_3d_vector() {
+            return Err("Quaternion rotation only defined for 3D vectors (length 3)".to_string());
+        }
+
+        let p = Quaternion {
+            w: 0.0,
+            x: v.data[0],
+            y: v.data[1],
+            z: v.data[2],
+        };
+        let rotated = self.multiply(&p).multiply(&self.conjugate());
+
+        Ok(Matrix {
+            data: vec![rotated.x, rotated.y, rotated.z],
+            rows: v.rows,
+            columns: v.columns,
+        })
+    }
+
+    /// Converts this (unit) quaternion to a 3x3 rotation matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, Quaternion};
+    /// assert_eq!(Quaternion::identity().to_rotation_matrix(), Matrix::identity(3));
+    /// ```
+    pub fn to_rotation_matrix(&self) -> Matrix {
+        let Quaternion { w, x, y, z } = *self;
+
+        Matrix {
+            data: vec![
+                1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y),
+                2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x),
+                2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y),
+            ],
+            rows: 3,
+            columns: 3,
+        }
+    }
+
+    /// Spherically interpolates between two unit quaternions
+    ///
+    /// Follows the shortest arc (flipping the sign of `other` when needed) and
+    /// falls back to normalized linear interpolation when the rotations are
+    /// nearly identical. `t` is not clamped, so values outside `[0, 1]`
+    /// extrapolate along the same arc.
+    ///
+    /// # Arguments
+    /// * `other` - Target rotation (reached at `t = 1`)
+    /// * `t` - Interpolation parameter
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, Quaternion};
+    /// use std::f64::consts::FRAC_PI_2;
+    /// let z = Matrix { data: vec![0.0, 0.0, 1.0], rows: 3, columns: 1 };
+    /// let end = Quaternion::from_axis_angle(&z, FRAC_PI_2).unwrap();
+    /// let half = Quaternion::identity().slerp(&end, 0.5);
+    /// let expected = Quaternion::from_axis_angle(&z, FRAC_PI_2 / 2.0).unwrap();
+    /// assert!((half.w - expected.w).abs() < 1e-10);
+    /// assert!((half.z - expected.z).abs() < 1e-10);
+    /// ```
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Self {
+        let mut end = *other;
+        let mut cos_theta = self.w * end.w + self.x * end.x + self.y * end.y + self.z * end.z;
+
+        // q and -q are the same rotation; take the shorter way round
+        if cos_theta < 0.0 {
+            end = Quaternion { w: -end.w, x: -end.x, y: -end.y, z: -end.z };
+            cos_theta = -cos_theta;
+        }
+
+        let (a, b) = if cos_theta > 1.0 - 1e-6 {
+            (1.0 - t, t)
+        } else {
+            let theta = cos_theta.acos();
+            let sin_theta = theta.sin();
+            (((1.0 - t) * theta).sin() / sin_theta, (t * theta).sin() / sin_theta)
+        };
+
+        let mut result = Quaternion {
+            w: a * self.w + b * end.w,
+            x: a * self.x + b * end.x,
+            y: a * self.y + b * end.y,
+            z: a * self.z + b * end.z,
+        };
+        // Only the linear fallback can drift off the unit sphere
+        if result.normalize().is_err() {
+            return *self;
+        }
+        result
+    }
+}
@@ -0,0 +1,85 @@
+use crate::core::Matrix;
+
+/// Size of the `rows` and `columns` header fields, each a little-endian `u64`
+const HEADER_FIELD_BYTES: usize = 8;
+
+impl Matrix {
+    /// Serializes the matrix into a compact binary layout
+    ///
+    /// The layout is `rows` and `columns` as little-endian `u64`, followed by the
+    /// `rows * columns` elements in row-major order as little-endian `f64`. Values
+    /// are stored bit-for-bit, so `NaN` payloads and signed zeros survive.
+    ///
+    /// # Returns
+    /// Byte vector of length `16 + 8 * rows * columns`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let bytes = Matrix::identity(2).to_bytes();
+    /// assert_eq!(bytes.len(), 16 + 4 * 8);
+    /// assert_eq!(&bytes[..8], &2u64.to_le_bytes());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 * HEADER_FIELD_BYTES + 8 * self.data.len());
+        bytes.extend_from_slice(&(self.rows as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.columns as u64).to_le_bytes());
+        for value in &self.data {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Deserializes a matrix written by [`Matrix::to_bytes`]
+    ///
+    /// # Arguments
+    /// * `bytes` - Header followed by the element data
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` if the header is complete and the data length matches it
+    /// - `Err` if the input is truncated, has trailing bytes, or declares
+    ///   dimensions that don't fit in memory
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, -2.5], rows: 1, columns: 2 };
+    /// assert_eq!(Matrix::from_bytes(&m.to_bytes()).unwrap(), m);
+    /// assert!(Matrix::from_bytes(&[0; 4]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < 2 * HEADER_FIELD_BYTES {
+            return Err(format!(
+                "Expected at least {} header bytes, got {}",
+                2 * HEADER_FIELD_BYTES, bytes.len()
+            ));
+        }
+
+        let (header, body) = bytes.split_at(2 * HEADER_FIELD_BYTES);
+        let read_u64 = |field: &[u8]| u64::from_le_bytes(field.try_into().unwrap());
+        let rows = read_u64(&header[..HEADER_FIELD_BYTES]);
+        let columns = read_u64(&header[HEADER_FIELD_BYTES..]);
+
+        let expected = usize::try_from(rows)
+            .ok()
+            .zip(usize::try_from(columns).ok())
+            .and_then(|(r, c)| r.checked_mul(c))
+            .and_then(|count| count.checked_mul(8))
+            .ok_or_else(|| format!("Declared dimensions {}x{} are too large", rows, columns))?;
+        if body.len() != expected {
+            return Err(format!(
+                "Expected {} data bytes for {}x{} matrix, got {}",
+                expected, rows, columns, body.len()
+            ));
+        }
+
+        Ok(Matrix {
+            data: body
+                .chunks_exact(8)
+                .map(|chunk| f64::from_le_bytes(chunk.try_into().unwrap()))
+                .collect(),
+            rows: rows as usize,
+            columns: columns as usize,
+        })
+    }
+}
@@ -0,0 +1,194 @@
+use crate::core::Matrix;
+
+impl Matrix {
+    /// Computes the Euclidean distances between all pairs of rows
+    ///
+    /// Each row is treated as a point; the result is the symmetric `rows x rows`
+    /// matrix whose `(i, j)` entry is the distance between points `i` and `j`.
+    ///
+    /// # Returns
+    /// Distance matrix with a zero diagonal
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let points = Matrix { data: vec![0.0, 0.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// let d = points.pairwise_distances();
+    /// assert_eq!(d.data, vec![0.0, 5.0, 5.0, 0.0]);
+    /// ```
+    pub fn pairwise_distances(&self) -> Self {
+        let n = self.rows;
+        let mut data = vec![0.0; n * n];
+
+        for i in 0..n {
+            for j in i + 1..n {
+                let distance = self[i]
+                    .iter()
+                    .zip(self[j].iter())
+                    .map(|(&a, &b)| (a - b) * (a - b))
+                    .sum::<f64>()
+                    .sqrt();
+                data[i * n + j] = distance;
+                data[j * n + i] = distance;
+            }
+        }
+
+        Matrix {
+            data,
+            rows: n,
+            columns: n,
+        }
+    }
+
+    /// Counts elements into equal-width bins over `[min, max]`
+    ///
+    /// Bin `i` covers `[min + i*w, min + (i+1)*w)` with `w = (max - min) / bins`;
+    /// the last bin also includes `max` itself. Elements outside `[min, max]`
+    /// (and `NaN`) are ignored rather than clamped into the end bins.
+    ///
+    /// # Arguments
+    /// * `bins` - Number of bins
+    /// * `min` - Lower edge of the first bin
+    /// * `max` - Upper edge of the last bin
+    ///
+    /// # Returns
+    /// Count per bin; all counts are zero if `max <= min`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![0.0, 0.4, 0.6, 1.0, 2.0], rows: 1, columns: 5 };
+    /// assert_eq!(m.histogram(2, 0.0, 1.0), vec![2, 2]);
+    /// ```
+    pub fn histogram(&self, bins: usize, min: f64, max: f64) -> Vec<usize> {
+        let mut counts = vec![0; bins];
+        if bins == 0 || max <= min {
+            return counts;
+        }
+
+        let width = (max - min) / bins as f64;
+        for &x in &self.data {
+            if !(min..=max).contains(&x) {
+                continue;
+            }
+            let bin = (((x - min) / width) as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+
+        counts
+    }
+
+    /// Finds the column index of the maximum element in each row
+    ///
+    /// Ties resolve to the first occurrence; `NaN` elements are never selected
+    /// unless a row consists only of them.
+    ///
+    /// # Returns
+    /// One column index per row
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let logits = Matrix { data: vec![0.1, 2.0, 0.5, 3.0, 1.0, 3.0], rows: 2, columns: 3 };
+    /// assert_eq!(logits.argmax_rows(), vec![1, 0]);
+    /// ```
+    pub fn argmax_rows(&self) -> Vec<usize> {
+        (0..self.rows)
+            .map(|row| Self::argmax((0..self.columns).map(|col| self.data[row * self.columns + col])))
+            .collect()
+    }
+
+    /// Finds the row index of the maximum element in each column
+    ///
+    /// Ties resolve to the first occurrence; `NaN` elements are never selected
+    /// unless a column consists only of them.
+    ///
+    /// # Returns
+    /// One row index per column
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![0.1, 2.0, 0.5, 3.0, 1.0, 3.0], rows: 2, columns: 3 };
+    /// assert_eq!(m.argmax_columns(), vec![1, 0, 1]);
+    /// ```
+    pub fn argmax_columns(&self) -> Vec<usize> {
+        (0..self.columns)
+            .map(|col| Self::argmax((0..self.rows).map(|row| self.data[row * self.columns + col])))
+            .collect()
+    }
+
+    /// Position of the first maximum in a sequence (0 for an empty sequence)
+    fn argmax(values: impl Iterator<Item = f64>) -> usize {
+        let mut best: Option<(usize, f64)> = None;
+        for (index, value) in values.enumerate() {
+            match best {
+                Some((_, max)) if !(value > max || max.is_nan()) => {}
+                _ => best = Some((index, value)),
+            }
+        }
+        best.map_or(0, |(index, _)| index)
+    }
+
+    /// Principal component analysis treating rows as samples and columns as features
+    ///
+    /// Centers each column, forms the sample covariance `X^T X / (n - 1)` and
+    /// diagonalizes it with Jacobi rotations. Each component's sign is chosen so its
+    /// largest-magnitude loading is positive, making the projection deterministic.
+    ///
+    /// # Arguments
+    /// * `components` - Number of leading principal components to keep
+    ///
+    /// # Returns
+    /// - `Ok((projected, variances))` where `projected` is `rows x components` (the
+    ///   centered data expressed in the component basis) and `variances` holds the
+    ///   explained variance of each component in descending order
+    /// - `Err` if `components` exceeds the number of features or there are fewer than two samples
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// // Points on the line y = x
+    /// let data = Matrix { data: vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0], rows: 3, columns: 2 };
+    /// let (projected, variances) = data.pca(1).unwrap();
+    /// assert_eq!((projected.rows, projected.columns), (3, 1));
+    /// assert!((variances[0] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn pca(&self, components: usize) -> Result<(Matrix, Vec<f64>), String> {
+        if components > self.columns {
+            return Err(format!(
+                "Cannot keep {} components from {} features",
+                components, self.columns
+            ));
+        }
+        if self.rows < 2 {
+            return Err("PCA requires at least two samples".to_string());
+        }
+
+        let n = self.columns;
+        let centered = self.subtract_row_vector(&self.column_means())?;
+        let covariance = centered
+            .transpose()
+            .multiply(&centered)?
+            .scalar_division((self.rows - 1) as f64);
+
+        let mut vectors = Matrix::identity(n);
+        let eigenvalues = covariance.jacobi_eigen(Some(&mut vectors));
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| eigenvalues[b].total_cmp(&eigenvalues[a]));
+
+        let mut basis = Matrix::zeros(n, components);
+        for (dst, &src) in order.iter().take(components).enumerate() {
+            let column: Vec<f64> = (0..n).map(|k| vectors.data[k * n + src]).collect();
+            let pivot = column.iter().fold(0.0_f64, |best, &x| if x.abs() > best.abs() { x } else { best });
+            let sign = if pivot < 0.0 { -1.0 } else { 1.0 };
+            for (k, x) in column.into_iter().enumerate() {
+                basis.data[k * components + dst] = sign * x;
+            }
+        }
+
+        let projected = centered.multiply(&basis)?;
+        let variances = order.iter().take(components).map(|&j| eigenvalues[j]).collect();
+        Ok((projected, variances))
+    }
+}
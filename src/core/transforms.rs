@@ -0,0 +1,646 @@
+use crate::core::Matrix;
+
+/// Boundary handling for 1D convolutions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    /// Zero-pad so the output is as wide as the input (centered on the full convolution)
+    Same,
+    /// Only keep positions where the kernel fully overlaps the input
+    Valid,
+}
+
+impl Matrix {
+    /// Rotates a 3D vector around an arbitrary axis using Rodrigues' formula
+    ///
+    /// Computes `v cosθ + (k × v) sinθ + k (k · v)(1 - cosθ)` where `k` is the
+    /// normalized axis. Positive angles rotate counter-clockwise when looking
+    /// down the axis towards the origin.
+    ///
+    /// # Arguments
+    /// * `axis` - 3D vector giving the rotation axis (need not be unit length)
+    /// * `angle` - Rotation angle in radians
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the rotated vector, with the same shape as `self`
+    /// - `Err` if either input isn't a 3D vector or the axis has zero length
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// let x = Matrix { data: vec![1.0, 0.0, 0.0], rows: 3, columns: 1 };
+    /// let z = Matrix { data: vec![0.0, 0.0, 1.0], rows: 3, columns: 1 };
+    /// let rotated = x.rotate_around_axis(&z, FRAC_PI_2).unwrap();
+    /// assert!((rotated.data[1] - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn rotate_around_axis(&self, axis: &Matrix, angle: f64) -> Result<Self, String> {
+        if !self.check_3d_vector() || !axis.check_3d_vector() {
+            return Err("Rotation around an axis only defined for 3D vectors (length 3)".to_string());
+        }
+
+        // Normalized axis with the same orientation (row/column) as self
+        let k = Matrix {
+            data: axis.unit_vector()?.data,
+            rows: self.rows,
+            columns: self.columns,
+        };
+
+        let (sin, cos) = angle.sin_cos();
+        let k_cross_v = k.cross_product(self)?;
+        let k_dot_v = k.dot_product(self)?;
+
+        let data = (0..3)
+            .map(|i| self.data[i] * cos + k_cross_v.data[i] * sin + k.data[i] * k_dot_v * (1.0 - cos))
+            .collect();
+
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            columns: self.columns,
+        })
+    }
+
+    /// Clamps every element to the range `[min, max]`
+    ///
+    /// Applies `f64::clamp` per element, so vectors (1xN or Nx1) get their
+    /// components bounded and `NaN` elements stay `NaN`.
+    ///
+    /// # Arguments
+    /// * `min` - Lower bound
+    /// * `max` - Upper bound
+    ///
+    /// # Panics
+    /// If `min > max`, `min` is `NaN` or `max` is `NaN`, like `f64::clamp`
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let v = Matrix { data: vec![-5.0, 0.5, 5.0], rows: 3, columns: 1 };
+    /// assert_eq!(v.clamp(0.0, 1.0).data, vec![0.0, 0.5, 1.0]);
+    /// ```
+    pub fn clamp(&self, min: f64, max: f64) -> Self {
+        Matrix {
+            data: self.data.iter().map(|x| x.clamp(min, max)).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+
+    /// Creates a mask marking elements greater than a threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Value elements are compared against
+    ///
+    /// # Returns
+    /// Matrix of the same shape with `1.0` where `a_ij > threshold` and `0.0` elsewhere
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let v = Matrix { data: vec![-1.0, 0.0, 1.0], rows: 3, columns: 1 };
+    /// assert_eq!(v.mask_gt(0.0).data, vec![0.0, 0.0, 1.0]);
+    /// ```
+    pub fn mask_gt(&self, threshold: f64) -> Self {
+        self.scalar_operation(threshold, |a, t| if a > t { 1.0 } else { 0.0 })
+    }
+
+    /// Picks elements from `self` or `other` according to a mask
+    ///
+    /// # Arguments
+    /// * `mask` - Selector; non-zero entries take `self`, zero entries take `other`
+    /// * `other` - Matrix supplying the elements where the mask is zero
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` with the selected elements if all dimensions match
+    /// - `Err` if dimensions mismatch
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let v = Matrix { data: vec![-1.0, 0.0, 1.0], rows: 3, columns: 1 };
+    /// let zeros = Matrix::zeros(3, 1);
+    /// // ReLU: keep positive components, zero the rest
+    /// let relu = v.select(&v.mask_gt(0.0), &zeros).unwrap();
+    /// assert_eq!(relu.data, vec![0.0, 0.0, 1.0]);
+    /// ```
+    pub fn select(&self, mask: &Matrix, other: &Matrix) -> Result<Self, String> {
+        self.check_dimensions_match(mask)?;
+        self.check_dimensions_match(other)?;
+
+        let data = self
+            .data
+            .iter()
+            .zip(mask.data.iter())
+            .zip(other.data.iter())
+            .map(|((&a, &m), &b)| if m != 0.0 { a } else { b })
+            .collect();
+
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            columns: self.columns,
+        })
+    }
+
+    /// Computes the 2D cross-correlation with a kernel
+    ///
+    /// Slides the (unflipped) kernel over the matrix with stride 1, as image filters
+    /// and convolutional layers do: `out[i][j] = Σ_{u,v} kernel[u][v] * x[i + u - p][j + v - p]`,
+    /// where entries outside the matrix count as zero. The output has
+    /// `rows + 2p - kernel.rows + 1` rows and `columns + 2p - kernel.columns + 1` columns,
+    /// so `padding = (k - 1) / 2` keeps the size for an odd `k x k` kernel.
+    ///
+    /// # Arguments
+    /// * `kernel` - Filter weights
+    /// * `padding` - Number of zero rows/columns added on every side
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the filtered matrix
+    /// - `Err` if the kernel is empty or larger than the padded matrix
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let image = Matrix { data: (1..=9).map(|x| x as f64).collect(), rows: 3, columns: 3 };
+    /// let box_filter = Matrix::ones(2, 2);
+    /// assert_eq!(image.correlate2d(&box_filter, 0).unwrap().data, vec![12.0, 16.0, 24.0, 28.0]);
+    /// ```
+    pub fn correlate2d(&self, kernel: &Matrix, padding: usize) -> Result<Self, String> {
+        if kernel.data.is_empty() {
+            return Err("Correlation kernel must not be empty".to_string());
+        }
+
+        let (padded_rows, padded_columns) = (self.rows + 2 * padding, self.columns + 2 * padding);
+        if kernel.rows > padded_rows || kernel.columns > padded_columns {
+            return Err(format!(
+                "Kernel {}x{} does not fit in padded {}x{} matrix",
+                kernel.rows, kernel.columns, padded_rows, padded_columns
+            ));
+        }
+
+        let rows = padded_rows - kernel.rows + 1;
+        let columns = padded_columns - kernel.columns + 1;
+        let mut data = Vec::with_capacity(rows * columns);
+        for i in 0..rows {
+            for j in 0..columns {
+                let mut sum = 0.0;
+                for u in 0..kernel.rows {
+                    // Row in the unpadded matrix, skipping the zero border
+                    let Some(r) = (i + u).checked_sub(padding).filter(|&r| r < self.rows) else {
+                        continue;
+                    };
+                    for v in 0..kernel.columns {
+                        if let Some(c) = (j + v).checked_sub(padding).filter(|&c| c < self.columns) {
+                            sum += kernel.data[u * kernel.columns + v] * self.data[r * self.columns + c];
+                        }
+                    }
+                }
+                data.push(sum);
+            }
+        }
+
+        Ok(Matrix { data, rows, columns })
+    }
+
+    /// Max-pools the matrix with a square sliding window
+    ///
+    /// Windows start at multiples of `stride` and only windows lying entirely inside
+    /// the matrix are used, so the output has `(rows - window) / stride + 1` rows and
+    /// `(columns - window) / stride + 1` columns (integer division); trailing rows or
+    /// columns that don't fill a window are dropped. A matrix smaller than the window
+    /// yields an empty result.
+    ///
+    /// # Arguments
+    /// * `window` - Side length of the square window
+    /// * `stride` - Step between window positions, in both directions
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the maximum of each window
+    /// - `Err` if `window` or `stride` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: (1..=9).map(|x| x as f64).collect(), rows: 3, columns: 3 };
+    /// assert_eq!(m.max_pool(2, 1).unwrap().data, vec![5.0, 6.0, 8.0, 9.0]);
+    /// ```
+    pub fn max_pool(&self, window: usize, stride: usize) -> Result<Self, String> {
+        self.pool(window, stride, |values| values.fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Average-pools the matrix with a square sliding window
+    ///
+    /// Window placement and output size follow [`Matrix::max_pool`].
+    ///
+    /// # Arguments
+    /// * `window` - Side length of the square window
+    /// * `stride` - Step between window positions, in both directions
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the mean of each window
+    /// - `Err` if `window` or `stride` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 6.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.avg_pool(2, 2).unwrap().data, vec![3.0]);
+    /// ```
+    pub fn avg_pool(&self, window: usize, stride: usize) -> Result<Self, String> {
+        let count = (window * window) as f64;
+        self.pool(window, stride, |values| values.sum::<f64>() / count)
+    }
+
+    /// Reduces every full `window x window` block at `stride` spacing to one value
+    fn pool<F>(&self, window: usize, stride: usize, reduce: F) -> Result<Self, String>
+    where
+        F: Fn(&mut dyn Iterator<Item = f64>) -> f64,
+    {
+        if window == 0 || stride == 0 {
+            return Err("Pooling window and stride must be positive".to_string());
+        }
+
+        let output_size = |size: usize| if size >= window { (size - window) / stride + 1 } else { 0 };
+        let (rows, columns) = (output_size(self.rows), output_size(self.columns));
+
+        let mut data = Vec::with_capacity(rows * columns);
+        for i in 0..rows {
+            for j in 0..columns {
+                let (top, left) = (i * stride, j * stride);
+                let mut values = (top..top + window)
+                    .flat_map(|r| self.data[r * self.columns + left..r * self.columns + left + window].iter().copied());
+                data.push(reduce(&mut values));
+            }
+        }
+
+        Ok(Matrix { data, rows, columns })
+    }
+
+    /// Zeroes every element outside a diagonal band
+    ///
+    /// Element `(i, j)` is kept when `i - lower <= j <= i + upper`, so the main
+    /// diagonal is always kept. Non-square matrices are supported.
+    ///
+    /// # Arguments
+    /// * `lower` - Number of subdiagonals to keep
+    /// * `upper` - Number of superdiagonals to keep
+    ///
+    /// # Returns
+    /// Matrix of the same shape containing only the band
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix::ones(3, 3);
+    /// assert_eq!(m.extract_band(0, 1).data, vec![1.0, 1.0, 0.0,
+    ///                                            0.0, 1.0, 1.0,
+    ///                                            0.0, 0.0, 1.0]);
+    /// ```
+    pub fn extract_band(&self, lower: usize, upper: usize) -> Self {
+        let mut result = self.clone();
+        for (index, value) in result.data.iter_mut().enumerate() {
+            let (i, j) = (index / self.columns, index % self.columns);
+            if j + lower < i || i + upper < j {
+                *value = 0.0;
+            }
+        }
+        result
+    }
+
+    /// Scales each row so its absolute values sum to one
+    ///
+    /// Rows of non-negative values become probability distributions. Rows whose
+    /// L1 norm is zero are left as zeros.
+    ///
+    /// # Returns
+    /// Matrix of the same shape with unit-L1 (or zero) rows
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let counts = Matrix { data: vec![1.0, 3.0, 0.0, 0.0], rows: 2, columns: 2 };
+    /// assert_eq!(counts.normalize_rows_l1().data, vec![0.25, 0.75, 0.0, 0.0]);
+    /// ```
+    pub fn normalize_rows_l1(&self) -> Self {
+        self.normalize_rows_by(|row| row.iter().map(|x| x.abs()).sum())
+    }
+
+    /// Scales each row to unit Euclidean length
+    ///
+    /// Rows whose L2 norm is zero are left as zeros.
+    ///
+    /// # Returns
+    /// Matrix of the same shape with unit-L2 (or zero) rows
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![3.0, 4.0, 0.0, 0.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.normalize_rows_l2().data, vec![0.6, 0.8, 0.0, 0.0]);
+    /// ```
+    pub fn normalize_rows_l2(&self) -> Self {
+        self.normalize_rows_by(|row| row.iter().map(|x| x * x).sum::<f64>().sqrt())
+    }
+
+    /// Balances a positive matrix into a doubly stochastic one (Sinkhorn-Knopp)
+    ///
+    /// Alternately rescales the rows and then the columns to sum to one until
+    /// every row and column sum is within `tol` of 1.
+    ///
+    /// # Arguments
+    /// * `iterations` - Maximum number of row/column normalization rounds
+    /// * `tol` - Maximum allowed deviation of each row and column sum from 1
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the doubly stochastic matrix
+    /// - `Err` if matrix isn't square, has a non-positive entry, or doesn't converge
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// let p = m.sinkhorn(100, 1e-10).unwrap();
+    /// assert!(p.is_doubly_stochastic(1e-10));
+    /// ```
+    pub fn sinkhorn(&self, iterations: usize, tol: f64) -> Result<Self, String> {
+        self.check_square()?;
+        if !self.data.iter().all(|&x| x > 0.0) {
+            return Err("Sinkhorn normalization requires all entries to be positive".to_string());
+        }
+
+        let n = self.rows;
+        let mut result = self.clone();
+
+        for _ in 0..iterations {
+            if result.is_doubly_stochastic(tol) {
+                return Ok(result);
+            }
+
+            result = result.normalize_rows_l1();
+            for col in 0..n {
+                let sum: f64 = (0..n).map(|row| result.data[row * n + col]).sum();
+                for row in 0..n {
+                    result.data[row * n + col] /= sum;
+                }
+            }
+        }
+
+        if result.is_doubly_stochastic(tol) {
+            return Ok(result);
+        }
+        Err(format!("Sinkhorn normalization did not converge in {} iterations", iterations))
+    }
+
+    /// Divides each row by the norm computed for it, skipping zero-norm rows
+    fn normalize_rows_by<F>(&self, norm: F) -> Self
+    where
+        F: Fn(&[f64]) -> f64,
+    {
+        let mut result = self.clone();
+        if self.columns == 0 {
+            return result;
+        }
+
+        for row in result.data.chunks_mut(self.columns) {
+            let n = norm(row);
+            if n != 0.0 {
+                row.iter_mut().for_each(|x| *x /= n);
+            }
+        }
+        result
+    }
+
+    /// Computes the direction of a 2D vector in degrees
+    ///
+    /// Measured counter-clockwise from the positive x-axis via `atan2`.
+    ///
+    /// # Returns
+    /// - `Ok(f64)` containing the angle in `(-180, 180]`
+    /// - `Err` if matrix isn't a 2D vector (length 2)
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let up = Matrix { data: vec![0.0, 1.0], rows: 2, columns: 1 };
+    /// assert_eq!(up.angle_degrees().unwrap(), 90.0);
+    /// ```
+    pub fn angle_degrees(&self) -> Result<f64, String> {
+        if !self.check_vector() || self.data.len() != 2 {
+            return Err("Angle only defined for 2D vectors (length 2)".to_string());
+        }
+
+        Ok(self.data[1].atan2(self.data[0]).to_degrees())
+    }
+
+    /// Spherically interpolates between two unit vectors
+    ///
+    /// Moves along the great-circle arc from `self` (at `t = 0`) to `other`
+    /// (at `t = 1`) at constant angular speed. When the vectors are nearly
+    /// parallel or antiparallel the arc is ill-defined, so the result falls back
+    /// to the linear interpolation `(1 - t) a + t b`.
+    ///
+    /// # Arguments
+    /// * `other` - Target unit vector with the same shape as `self`
+    /// * `t` - Interpolation parameter
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` containing the interpolated vector
+    /// - `Err` if inputs aren't vectors or their shapes differ
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let x = Matrix { data: vec![1.0, 0.0], rows: 2, columns: 1 };
+    /// let y = Matrix { data: vec![0.0, 1.0], rows: 2, columns: 1 };
+    /// let mid = x.slerp(&y, 0.5).unwrap();
+    /// assert!((mid.angle_degrees().unwrap() - 45.0).abs() < 1e-10);
+    /// ```
+    pub fn slerp(&self, other: &Matrix, t: f64) -> Result<Self, String> {
+        let cos_theta = self.dot_product(other)?.clamp(-1.0, 1.0);
+
+        let (a, b) = if cos_theta.abs() > 1.0 - 1e-6 {
+            (1.0 - t, t)
+        } else {
+            let theta = cos_theta.acos();
+            let sin_theta = theta.sin();
+            (((1.0 - t) * theta).sin() / sin_theta, (t * theta).sin() / sin_theta)
+        };
+
+        self.scalar_multiplication(a).addition(&other.scalar_multiplication(b))
+    }
+
+    /// Convolves every row with a 1D kernel
+    ///
+    /// Computes the true convolution `y[n] = Σ_k kernel[k] * x[n - k]` (the kernel
+    /// is flipped), so symmetric kernels such as moving averages behave as expected.
+    /// With `Padding::Same` the input is zero-padded and the output keeps `columns`
+    /// entries, taken from the full convolution starting at offset `(k - 1) / 2`.
+    /// With `Padding::Valid` only the `columns - k + 1` fully overlapping positions
+    /// are kept (zero columns if the kernel is longer than a row).
+    ///
+    /// # Arguments
+    /// * `kernel` - Convolution weights of length `k`
+    /// * `padding` - Boundary handling, see [`Padding`]
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` with `rows` rows and the output width described above
+    /// - `Err` if the kernel is empty
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::{Matrix, Padding};
+    /// let series = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 1, columns: 4 };
+    /// let smoothed = series.convolve_rows(&[0.5, 0.5], Padding::Valid).unwrap();
+    /// assert_eq!(smoothed.data, vec![1.5, 2.5, 3.5]);
+    /// ```
+    pub fn convolve_rows(&self, kernel: &[f64], padding: Padding) -> Result<Self, String> {
+        if kernel.is_empty() {
+            return Err("Convolution kernel must not be empty".to_string());
+        }
+
+        let k = kernel.len();
+        let (offset, width) = match padding {
+            Padding::Same => ((k - 1) / 2, self.columns),
+            Padding::Valid => (k - 1, (self.columns + 1).saturating_sub(k)),
+        };
+
+        let mut data = Vec::with_capacity(self.rows * width);
+        for row in 0..self.rows {
+            let x = &self.data[row * self.columns..(row + 1) * self.columns];
+            for out in 0..width {
+                // Index into the full convolution of length columns + k - 1
+                let n = out + offset;
+                let value: f64 = kernel
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j <= n && n - j < self.columns)
+                    .map(|(j, &w)| w * x[n - j])
+                    .sum();
+                data.push(value);
+            }
+        }
+
+        Ok(Matrix {
+            data,
+            rows: self.rows,
+            columns: width,
+        })
+    }
+
+    /// Stacks all entries into a single column vector
+    ///
+    /// Entries are taken in row-major order, so this is the inverse of
+    /// [`Matrix::unflatten`] with the original shape.
+    ///
+    /// # Returns
+    /// `(rows * columns) x 1` column vector of the matrix data
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 2, columns: 2 };
+    /// let v = m.flatten();
+    /// assert_eq!((v.rows, v.columns), (4, 1));
+    /// assert_eq!(v.data, m.data);
+    /// ```
+    pub fn flatten(&self) -> Self {
+        Matrix {
+            data: self.data.clone(),
+            rows: self.data.len(),
+            columns: 1,
+        }
+    }
+
+    /// Converts the matrix into a column vector for vector operations
+    ///
+    /// Same as [`Matrix::flatten`]; the result can be passed straight to
+    /// `dot_product`, `magnitude` and the other vector methods.
+    ///
+    /// # Returns
+    /// `(rows * columns) x 1` column vector of the row-major data
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    /// assert_eq!(m.to_vector().magnitude().unwrap(), 5.0);
+    /// ```
+    pub fn to_vector(&self) -> Self {
+        self.flatten()
+    }
+
+    /// Reinterprets the row-major data with new dimensions
+    ///
+    /// No element moves: reading the result row by row gives the same sequence as
+    /// reading `self` row by row.
+    ///
+    /// # Arguments
+    /// * `rows` - Number of rows of the result
+    /// * `columns` - Number of columns of the result
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of shape `rows x columns`
+    /// - `Err` if `rows * columns` differs from the number of elements
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let m = Matrix { data: (1..=12).map(f64::from).collect(), rows: 1, columns: 12 };
+    /// let r = m.reshape(3, 4).unwrap();
+    /// assert_eq!(r[1], [5.0, 6.0, 7.0, 8.0]);
+    /// ```
+    pub fn reshape(&self, rows: usize, columns: usize) -> Result<Self, String> {
+        Matrix::from_slice(rows, columns, &self.data)
+    }
+
+    /// Reshapes a vector into a matrix, filling it in row-major order
+    ///
+    /// # Arguments
+    /// * `vector` - Row or column vector with `rows * columns` entries
+    /// * `rows` - Number of rows of the result
+    /// * `columns` - Number of columns of the result
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)` of shape `rows x columns`
+    /// - `Err` if `vector` isn't a vector or its length doesn't match
+    ///
+    /// # Examples
+    /// ```
+    /// use nelab::Matrix;
+    /// let v = Matrix { data: vec![1.0, 2.0, 3.0, 4.0], rows: 4, columns: 1 };
+    /// let m = Matrix::unflatten(&v, 2, 2).unwrap();
+    /// assert_eq!(m[1], [3.0, 4.0]);
+    /// ```
+    pub fn unflatten(vector: &Matrix, rows: usize, columns: usize) -> Result<Self, String> {
+        if !vector.check_vector() {
+            return Err("Input must be a vector (1xN or Nx1)".to_string());
+        }
+        Matrix::from_slice(rows, columns, &vector.data)
+    }
+}
+
+/// Wraps an angle in degrees into `[0, 360)`
+///
+/// # Examples
+/// ```
+/// use nelab::normalize_angle;
+/// assert_eq!(normalize_angle(370.0), 10.0);
+/// assert_eq!(normalize_angle(-90.0), 270.0);
+/// ```
+pub fn normalize_angle(degrees: f64) -> f64 {
+    let wrapped = degrees.rem_euclid(360.0);
+    // Tiny negative inputs round up to exactly 360
+    if wrapped >= 360.0 { 0.0 } else { wrapped }
+}
+
+/// Wraps an angle in degrees into `[-180, 180)`
+///
+/// # Examples
+/// ```
+/// use nelab::normalize_angle_signed;
+/// assert_eq!(normalize_angle_signed(190.0), -170.0);
+/// assert_eq!(normalize_angle_signed(-180.0), -180.0);
+/// ```
+pub fn normalize_angle_signed(degrees: f64) -> f64 {
+    normalize_angle(degrees + 180.0) - 180.0
+}
@@ -1,4 +1,4 @@
 pub mod core;
 
-pub use core::Matrix;
+pub use core::{Matrix, Padding, Quaternion, VectorAccumulator, normalize_angle, normalize_angle_signed};
 
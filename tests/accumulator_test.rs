@@ -0,0 +1,48 @@
+use nelab::{Matrix, VectorAccumulator};
+
+use approx::assert_relative_eq;
+
+#[test]
+fn test_vector_accumulator() {
+    let samples = [
+        [1.0, 10.0, -3.0],
+        [2.0, 20.0, -3.0],
+        [4.0, 30.0, -3.0],
+        [5.0, 40.0, -3.0],
+    ];
+
+    let mut acc = VectorAccumulator::new(3);
+    assert_eq!(acc.mean(), Matrix::zeros(3, 1));
+    assert_eq!(acc.variance(), Matrix::zeros(3, 1));
+
+    for (i, sample) in samples.iter().enumerate() {
+        // Row and column vectors are both accepted
+        let v = if i % 2 == 0 {
+            Matrix { data: sample.to_vec(), rows: 1, columns: 3 }
+        } else {
+            Matrix { data: sample.to_vec(), rows: 3, columns: 1 }
+        };
+        acc.push(&v).unwrap();
+    }
+    assert_eq!(acc.count(), 4);
+
+    let mean = acc.mean();
+    assert_eq!((mean.rows, mean.columns), (3, 1));
+    let expected_mean = [3.0, 25.0, -3.0];
+    let expected_variance = [2.5, 125.0, 0.0];
+    for i in 0..3 {
+        assert_relative_eq!(mean.data[i], expected_mean[i], epsilon = 1e-12);
+        assert_relative_eq!(acc.variance().data[i], expected_variance[i], epsilon = 1e-12);
+    }
+
+    // Large offset: Welford avoids catastrophic cancellation
+    let mut shifted = VectorAccumulator::new(1);
+    for x in [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0] {
+        shifted.push(&Matrix { data: vec![x], rows: 1, columns: 1 }).unwrap();
+    }
+    assert_relative_eq!(shifted.variance().data[0], 22.5, epsilon = 1e-6);
+
+    assert!(acc.push(&Matrix::ones(2, 1)).is_err());
+    assert!(acc.push(&Matrix::ones(2, 2)).is_err());
+    assert_eq!(acc.count(), 4);
+}
@@ -230,9 +230,17 @@ fn test_matrix_power() {
     };
 
     // Square of matrix
-    let squared = m.multiply(&m).unwrap();
+    let squared = m.pow(2).unwrap();
     assert_eq!(squared.data, vec![7.0, 10.0, 15.0, 22.0]);
 
+    assert_eq!(m.pow(0).unwrap(), Matrix::identity(2));
+    assert_eq!(m.pow(1).unwrap(), m);
+    let cubed = m.multiply(&m).unwrap().multiply(&m).unwrap();
+    assert_eq!(m.pow(3).unwrap(), cubed);
+    assert_eq!(cubed.data, vec![37.0, 54.0, 81.0, 118.0]);
+
+    assert!(Matrix::ones(2, 3).pow(0).is_err());
+
     // Multiply by identity should return original
     let identity = Matrix::identity(2);
     let product = m.multiply(&identity).unwrap();
@@ -383,4 +391,2670 @@ fn test_scalar_projection() {
     let vec_proj = v2.vector_projection_onto(&v1).unwrap();
     assert_relative_eq!(vec_proj.data[0], 3.0, epsilon = 1e-10);
     assert_relative_eq!(vec_proj.data[1], 0.0, epsilon = 1e-10);
-}
\ No newline at end of file
+}
+#[test]
+fn test_orientation() {
+    // Reflection across the x-axis flips handedness
+    let reflection = Matrix::diagonal(&[1.0, -1.0, 1.0]);
+    assert_eq!(reflection.orientation().unwrap(), -1);
+
+    // Row swap (odd permutation) is also a reflection
+    let swap = Matrix {
+        data: vec![0.0, 1.0, 1.0, 0.0],
+        rows: 2,
+        columns: 2,
+    };
+    assert_eq!(swap.orientation().unwrap(), -1);
+
+    assert_eq!(Matrix::identity(4).orientation().unwrap(), 1);
+    assert_eq!(test_matrix().orientation().unwrap(), -1);
+
+    // Singular matrices have no orientation
+    let singular = Matrix {
+        data: vec![1.0, 2.0, 2.0, 4.0],
+        rows: 2,
+        columns: 2,
+    };
+    assert_eq!(singular.orientation().unwrap(), 0);
+
+    // Determinant overflows but the sign is still available
+    let huge = Matrix::diagonal(&[1e200, -1e200, 1e200]);
+    assert_eq!(huge.orientation().unwrap(), -1);
+
+    // The singularity test is relative, so a well-conditioned but tiny matrix still has one
+    let tiny = Matrix::identity(3).scalar_multiplication(-1e-17);
+    assert_eq!(tiny.orientation().unwrap(), -1);
+    let (log_det, sign) = tiny.log_abs_determinant().unwrap();
+    assert_relative_eq!(log_det, 3.0 * 1e-17_f64.ln(), epsilon = 1e-12);
+    assert_eq!(sign, -1);
+    let x = tiny.solve(&Matrix::ones(3, 1).scalar_multiplication(1e-17)).unwrap();
+    assert!(x.data.iter().all(|&v| (v + 1.0).abs() < 1e-12));
+
+    // One huge off-diagonal entry does not make a unit triangular matrix singular
+    let sheared = Matrix {
+        data: vec![1.0, 1e16, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_eq!(sheared.orientation().unwrap(), 1);
+    assert_eq!(sheared.log_abs_determinant().unwrap(), (0.0, 1));
+    let x = sheared.solve(&Matrix { data: vec![1e16, 1.0, 1.0], rows: 3, columns: 1 }).unwrap();
+    assert_eq!(x.data, vec![0.0, 1.0, 1.0]);
+
+    assert!(Matrix::zeros(2, 3).orientation().is_err());
+}
+
+#[test]
+fn test_frobenius_inner() {
+    let a = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        rows: 2,
+        columns: 3,
+    };
+    let b = Matrix {
+        data: vec![1.0, 0.0, -1.0, 2.0, 1.0, 0.0],
+        rows: 2,
+        columns: 3,
+    };
+
+    // <A, B> = tr(A^T B)
+    let inner = a.frobenius_inner(&b).unwrap();
+    let trace = a.transpose().multiply(&b).unwrap().trace().unwrap();
+    assert_relative_eq!(inner, trace, epsilon = 1e-10);
+    assert_eq!(inner, 11.0);
+
+    // <A, A> is the squared Frobenius norm
+    assert_eq!(a.frobenius_inner(&a).unwrap(), 91.0);
+
+    assert!(a.frobenius_inner(&a.transpose()).is_err());
+}
+
+#[test]
+fn test_magnitude_stable() {
+    let huge = Matrix {
+        data: vec![1e200, 0.0, 0.0],
+        rows: 3,
+        columns: 1,
+    };
+    let mag = huge.magnitude_stable().unwrap();
+    assert!(mag.is_finite());
+    assert_relative_eq!(mag, 1e200, max_relative = 1e-12);
+
+    // Matches the plain magnitude in the ordinary range
+    let v = Matrix {
+        data: vec![1.0, 2.0, 3.0],
+        rows: 1,
+        columns: 3,
+    };
+    assert_relative_eq!(v.magnitude_stable().unwrap(), v.magnitude().unwrap(), epsilon = 1e-12);
+
+    // Tiny components don't underflow to zero
+    let tiny = Matrix {
+        data: vec![3e-200, 4e-200],
+        rows: 2,
+        columns: 1,
+    };
+    assert_relative_eq!(tiny.magnitude_stable().unwrap(), 5e-200, max_relative = 1e-12);
+
+    assert_eq!(Matrix::zeros(3, 1).magnitude_stable().unwrap(), 0.0);
+    assert!(Matrix::zeros(2, 2).magnitude_stable().is_err());
+}
+
+#[test]
+fn test_zero_and_identity_predicates() {
+    let m = Matrix {
+        data: vec![4.0, 7.0, 2.0, 6.0],
+        rows: 2,
+        columns: 2,
+    };
+    let product = m.multiply(&m.inverse().unwrap()).unwrap();
+    assert!(product.is_identity(1e-10));
+    assert!(!m.is_identity(1e-10));
+
+    // Residual of A * A^-1 - I is approximately zero
+    let residual = product.subtraction(&Matrix::identity(2)).unwrap();
+    assert!(residual.is_zero(1e-10));
+    assert!(!m.is_zero(1e-10));
+
+    // Non-square matrices are never the identity
+    assert!(!Matrix::zeros(2, 3).is_identity(1.0));
+    assert!(Matrix::zeros(2, 3).is_zero(0.0));
+}
+
+#[test]
+fn test_checked_access() {
+    let mut m = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        rows: 2,
+        columns: 3,
+    };
+
+    assert_eq!(m.get(0, 0), Some(1.0));
+    assert_eq!(m.get(1, 2), Some(6.0));
+    assert_eq!(m.get(2, 0), None);
+    // Column overflow must not wrap into the next row
+    assert_eq!(m.get(0, 3), None);
+
+    *m.get_mut(1, 0).unwrap() = 10.0;
+    assert_eq!(m[1][0], 10.0);
+    assert!(m.get_mut(0, 3).is_none());
+    assert!(m.get_mut(5, 5).is_none());
+}
+
+#[test]
+fn test_transpose_in_place() {
+    let original = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        rows: 3,
+        columns: 3,
+    };
+    let mut m = original.clone();
+    m.transpose_in_place().unwrap();
+    assert_eq!(m, original.transpose());
+
+    let mut rect = Matrix::zeros(2, 3);
+    assert!(rect.transpose_in_place().is_err());
+    assert_eq!(rect.rows, 2);
+    assert_eq!(rect.columns, 3);
+}
+
+#[test]
+fn test_multiply_chain() {
+    let a = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        rows: 2,
+        columns: 3,
+    };
+    let b = Matrix {
+        data: vec![1.0, 0.0, -1.0, 2.0, 0.5, 1.0],
+        rows: 3,
+        columns: 2,
+    };
+    let c = Matrix {
+        data: vec![2.0, 1.0, 0.0, 1.0, 3.0, -2.0],
+        rows: 2,
+        columns: 3,
+    };
+
+    let chained = Matrix::multiply_chain(&[a.clone(), b.clone(), c.clone()]).unwrap();
+    let sequential = a.multiply(&b).unwrap().multiply(&c).unwrap();
+    assert_eq!(chained.rows, 2);
+    assert_eq!(chained.columns, 3);
+    for (x, y) in chained.data.iter().zip(sequential.data.iter()) {
+        assert_relative_eq!(x, y, epsilon = 1e-10);
+    }
+
+    // Single matrix chain is the matrix itself
+    assert_eq!(Matrix::multiply_chain(std::slice::from_ref(&a)).unwrap(), a);
+
+    // 1x2 * 2x1 * 1x2: (AB)C costs 4, A(BC) costs 8. The two orders round
+    // differently, so the result reveals which one was taken.
+    let row = Matrix { data: vec![1.0, 1.0], rows: 1, columns: 2 };
+    let column = Matrix { data: vec![1.0, f64::EPSILON / 2.0], rows: 2, columns: 1 };
+    let tail = Matrix { data: vec![3.0, 3.0], rows: 1, columns: 2 };
+    let left_first = row.multiply(&column).unwrap().multiply(&tail).unwrap();
+    let right_first = row.multiply(&column.multiply(&tail).unwrap()).unwrap();
+    assert_ne!(left_first, right_first);
+    let chain = [row, column, tail];
+    assert_eq!(Matrix::multiply_chain(&chain).unwrap(), left_first);
+
+    // 50x5 * 5x100 * 100x10: A(BC) costs 7500, (AB)C costs 75000
+    let chain = [Matrix::ones(50, 5), Matrix::ones(5, 100), Matrix::ones(100, 10)];
+    let product = Matrix::multiply_chain(&chain).unwrap();
+    assert_eq!(product.data, vec![500.0; 500]);
+
+    // Errors
+    assert!(Matrix::multiply_chain(&[]).is_err());
+    assert!(Matrix::multiply_chain(&[a.clone(), c]).is_err());
+}
+
+#[test]
+fn test_slice_accessors() {
+    let source = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let mut m = Matrix::from_slice(2, 3, &source).unwrap();
+    assert_eq!(m.rows, 2);
+    assert_eq!(m.columns, 3);
+    assert_eq!(m.as_slice(), &source);
+
+    m.as_mut_slice()[4] = 50.0;
+    assert_eq!(m[1][1], 50.0);
+
+    // Round trip through the slice accessors
+    let copy = Matrix::from_slice(m.rows, m.columns, m.as_slice()).unwrap();
+    assert_eq!(copy, m);
+
+    assert!(Matrix::from_slice(3, 3, &source).is_err());
+}
+
+#[test]
+fn test_checked_scalar_division() {
+    let m = test_matrix();
+
+    let divided = m.checked_scalar_division(2.0).unwrap();
+    assert_eq!(divided.data, vec![0.5, 1.0, 1.5, 2.0]);
+
+    assert!(m.checked_scalar_division(0.0).is_err());
+    assert!(m.checked_scalar_division(-0.0).is_err());
+
+    // Operator behavior is unchanged
+    let infinite = &m / 0.0;
+    assert!(infinite.data.iter().all(|x| x.is_infinite()));
+}
+
+#[test]
+fn test_eval_polynomial() {
+    let a = Matrix {
+        data: vec![2.0, -1.0, 0.0, 1.0, 3.0, 1.0, 0.5, 0.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+
+    // p(x) = x
+    assert_eq!(a.eval_polynomial(&[0.0, 1.0]).unwrap(), a);
+
+    // p(x) = 3 is 3I
+    assert_eq!(a.eval_polynomial(&[3.0]).unwrap(), &Matrix::identity(3) * 3.0);
+    // A constant polynomial never touches the matrix entries
+    let unbounded = &Matrix::ones(3, 3) * f64::INFINITY;
+    assert_eq!(unbounded.eval_polynomial(&[3.0]).unwrap(), &Matrix::identity(3) * 3.0);
+
+    // p(x) = 1 + 2x + x^3
+    let cubed = a.multiply(&a).unwrap().multiply(&a).unwrap();
+    let expected = (&(&Matrix::identity(3) + &(&a * 2.0)).unwrap() + &cubed).unwrap();
+    let value = a.eval_polynomial(&[1.0, 2.0, 0.0, 1.0]).unwrap();
+    for (x, y) in value.data.iter().zip(expected.data.iter()) {
+        assert_relative_eq!(x, y, epsilon = 1e-10);
+    }
+
+    // Empty polynomial is zero
+    assert!(a.eval_polynomial(&[]).unwrap().is_zero(0.0));
+
+    assert!(Matrix::ones(2, 3).eval_polynomial(&[1.0]).is_err());
+}
+
+#[test]
+fn test_log_abs_determinant() {
+    let m = Matrix {
+        data: vec![4.0, 3.0, 2.0, 1.0, 3.0, 1.0, 2.0, 1.0, 5.0],
+        rows: 3,
+        columns: 3,
+    };
+    let det = m.determinant().unwrap();
+    let (log_det, sign) = m.log_abs_determinant().unwrap();
+    assert_relative_eq!(log_det, det.abs().ln(), epsilon = 1e-10);
+    assert_eq!(sign as f64, det.signum());
+
+    let (log_det, sign) = test_matrix().log_abs_determinant().unwrap();
+    assert_relative_eq!(log_det, 2.0f64.ln(), epsilon = 1e-10);
+    assert_eq!(sign, -1);
+
+    // det = 1e-600 underflows f64, but the log stays finite
+    let small = &Matrix::identity(200) * 1e-3;
+    let (log_det, sign) = small.log_abs_determinant().unwrap();
+    assert_relative_eq!(log_det, 200.0 * 1e-3f64.ln(), epsilon = 1e-8);
+    assert_eq!(sign, 1);
+
+    let singular = Matrix {
+        data: vec![1.0, 2.0, 2.0, 4.0],
+        rows: 2,
+        columns: 2,
+    };
+    assert!(singular.log_abs_determinant().is_err());
+    assert!(Matrix::ones(2, 3).log_abs_determinant().is_err());
+}
+
+#[test]
+fn test_pairwise_distances() {
+    let points = Matrix {
+        data: vec![0.0, 0.0, 3.0, 0.0, 0.0, 4.0],
+        rows: 3,
+        columns: 2,
+    };
+    let d = points.pairwise_distances();
+
+    assert_eq!(d.rows, 3);
+    assert_eq!(d.columns, 3);
+    assert_eq!(d.data, vec![0.0, 3.0, 4.0, 3.0, 0.0, 5.0, 4.0, 5.0, 0.0]);
+    assert_eq!(d, d.transpose());
+}
+
+#[test]
+fn test_from_column_vectors() {
+    let vectors = [
+        Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 },
+        Matrix { data: vec![3.0, 4.0], rows: 2, columns: 1 },
+        Matrix { data: vec![5.0, 6.0], rows: 1, columns: 2 },
+    ];
+    let m = Matrix::from_column_vectors(&vectors).unwrap();
+    assert_eq!(m.rows, 2);
+    assert_eq!(m.columns, 3);
+    assert_eq!(m[0], [1.0, 3.0, 5.0]);
+    assert_eq!(m[1], [2.0, 4.0, 6.0]);
+
+    let empty = Matrix::from_column_vectors(&[]).unwrap();
+    assert!(empty.data.is_empty());
+    assert_eq!((empty.rows, empty.columns), (0, 0));
+
+    assert!(Matrix::from_column_vectors(&[Matrix::zeros(2, 1), Matrix::zeros(3, 1)]).is_err());
+    assert!(Matrix::from_column_vectors(&[Matrix::zeros(2, 2)]).is_err());
+}
+
+#[test]
+fn test_eigenvalues_symmetric() {
+    let diag = Matrix::diagonal(&[3.0, 1.0, 2.0]);
+    assert_eq!(diag.eigenvalues_symmetric().unwrap(), vec![3.0, 2.0, 1.0]);
+
+    // [[2, 1], [1, 2]] has eigenvalues 3 and 1
+    let m = Matrix {
+        data: vec![2.0, 1.0, 1.0, 2.0],
+        rows: 2,
+        columns: 2,
+    };
+    let values = m.eigenvalues_symmetric().unwrap();
+    assert_relative_eq!(values[0], 3.0, epsilon = 1e-10);
+    assert_relative_eq!(values[1], 1.0, epsilon = 1e-10);
+
+    // Eigenvalues sum to the trace and multiply to the determinant
+    let s = Matrix {
+        data: vec![4.0, 1.0, -2.0, 2.0,
+                   1.0, 2.0, 0.0, 1.0,
+                   -2.0, 0.0, 3.0, -2.0,
+                   2.0, 1.0, -2.0, -1.0],
+        rows: 4,
+        columns: 4,
+    };
+    let values = s.eigenvalues_symmetric().unwrap();
+    assert!(values.windows(2).all(|w| w[0] >= w[1]));
+    assert_relative_eq!(values.iter().sum::<f64>(), s.trace().unwrap(), epsilon = 1e-10);
+    assert_relative_eq!(values.iter().product::<f64>(), s.determinant().unwrap(), epsilon = 1e-9);
+
+    assert!(Matrix::zeros(2, 3).eigenvalues_symmetric().is_err());
+
+    let skewed = Matrix {
+        data: vec![1.0, 2.0, 2.0 + 1e-6, 1.0],
+        rows: 2,
+        columns: 2,
+    };
+    assert!(skewed.eigenvalues_symmetric().is_err());
+    assert!(!skewed.is_symmetric(1e-9));
+    assert!(skewed.is_symmetric(1e-5));
+}
+
+#[test]
+fn test_rotate_around_axis() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    let x = Matrix { data: vec![1.0, 0.0, 0.0], rows: 3, columns: 1 };
+    let z = Matrix { data: vec![0.0, 0.0, 2.0], rows: 3, columns: 1 };
+
+    let rotated = x.rotate_around_axis(&z, FRAC_PI_2).unwrap();
+    assert_relative_eq!(rotated.data[0], 0.0, epsilon = 1e-10);
+    assert_relative_eq!(rotated.data[1], 1.0, epsilon = 1e-10);
+    assert_relative_eq!(rotated.data[2], 0.0, epsilon = 1e-10);
+
+    // Rotating about a diagonal axis by 2π/3 cycles the coordinate axes
+    let diagonal_axis = Matrix { data: vec![1.0, 1.0, 1.0], rows: 1, columns: 3 };
+    let cycled = x.rotate_around_axis(&diagonal_axis, 2.0 * PI / 3.0).unwrap();
+    assert_relative_eq!(cycled.data[0], 0.0, epsilon = 1e-10);
+    assert_relative_eq!(cycled.data[1], 1.0, epsilon = 1e-10);
+    assert_relative_eq!(cycled.data[2], 0.0, epsilon = 1e-10);
+    assert_eq!((cycled.rows, cycled.columns), (3, 1));
+
+    // Length is preserved and the axis itself is fixed
+    let v = Matrix { data: vec![1.0, -2.0, 0.5], rows: 3, columns: 1 };
+    let r = v.rotate_around_axis(&diagonal_axis, 0.7).unwrap();
+    assert_relative_eq!(r.magnitude().unwrap(), v.magnitude().unwrap(), epsilon = 1e-10);
+    let fixed = z.rotate_around_axis(&z, 1.3).unwrap();
+    assert_relative_eq!(fixed.data[2], 2.0, epsilon = 1e-10);
+
+    assert!(x.rotate_around_axis(&Matrix::zeros(3, 1), 1.0).is_err());
+    assert!(Matrix::zeros(2, 1).rotate_around_axis(&z, 1.0).is_err());
+}
+
+#[test]
+fn test_skew_symmetric() {
+    let a = Matrix { data: vec![1.0, -2.0, 0.5], rows: 3, columns: 1 };
+    let b = Matrix { data: vec![4.0, 3.0, -1.0], rows: 3, columns: 1 };
+
+    let skew = Matrix::skew_symmetric(&a).unwrap();
+    assert_eq!(skew.multiply(&b).unwrap(), a.cross_product(&b).unwrap());
+
+    // [v]x^T = -[v]x
+    assert_eq!(skew.transpose(), -&skew);
+
+    assert!(Matrix::skew_symmetric(&Matrix::zeros(2, 1)).is_err());
+}
+
+#[test]
+fn test_rref_full_pivot() {
+    // Third row is r0/3 + r1/7, up to rounding
+    let r0 = [1.0 / 3.0 * 1e4, 2.0 / 7.0 * 1e4, 1.0 / 11.0 * 1e4];
+    let r1 = [1.0 / 13.0 * 1e4, 1.0 / 17.0 * 1e4, 3.0 / 19.0 * 1e4];
+    let r2: Vec<f64> = r0.iter().zip(r1.iter()).map(|(a, b)| a / 3.0 + b / 7.0).collect();
+    let mut data = r0.to_vec();
+    data.extend_from_slice(&r1);
+    data.extend_from_slice(&r2);
+    let m = Matrix { data, rows: 3, columns: 3 };
+
+    // Partial pivoting leaves a residual of a few ulps of the entries in the last
+    // row, which the scaled tolerance of rank() recognizes as zero
+    assert_eq!(m.rank(), 2);
+    assert_eq!(m.rref()[2], [0.0, 0.0, 0.0]);
+
+    let (r, row_perm, col_perm) = m.rref_full_pivot();
+    let rank = (0..3).filter(|&i| r[i][i] == 1.0).count();
+    assert_eq!(rank, 2);
+    assert_eq!(r[2], [0.0, 0.0, 0.0]);
+
+    // Permutations are genuine permutations
+    let mut sorted = row_perm.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 1, 2]);
+    let mut sorted = col_perm.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 1, 2]);
+
+    // The first pivot is the largest entry of the matrix
+    assert_eq!(row_perm[0], 0);
+    assert_eq!(col_perm[0], 0);
+
+    // Full rank rectangular matrix
+    let wide = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 7.0],
+        rows: 2,
+        columns: 3,
+    };
+    let (r, _, _) = wide.rref_full_pivot();
+    assert_relative_eq!(r[0][0], 1.0);
+    assert_relative_eq!(r[1][1], 1.0);
+    assert_relative_eq!(r[1][0], 0.0);
+    assert_relative_eq!(r[0][1], 0.0);
+
+    // Zero matrix has rank zero
+    let (r, _, _) = Matrix::zeros(2, 2).rref_full_pivot();
+    assert!(r.is_zero(0.0));
+}
+
+// Replays a step log produced by `reduce_with_steps`
+fn replay_steps(m: &Matrix, steps: &[String]) -> Matrix {
+    let row = |name: &str| name.trim_start_matches('R').parse::<usize>().unwrap() - 1;
+    let mut m = m.clone();
+
+    for step in steps {
+        let parts: Vec<&str> = step.split_whitespace().collect();
+        let target = row(parts[0]);
+        match parts[1] {
+            "<->" => {
+                let other = row(parts[2]);
+                for c in 0..m.columns {
+                    let tmp = m[target][c];
+                    m[target][c] = m[other][c];
+                    m[other][c] = tmp;
+                }
+            }
+            "/=" => {
+                let divisor: f64 = parts[2].parse().unwrap();
+                for c in 0..m.columns {
+                    m[target][c] /= divisor;
+                }
+            }
+            op => {
+                let (factor, source) = match parts[2].split_once('*') {
+                    Some((f, r)) => (f.parse::<f64>().unwrap(), row(r)),
+                    None => (1.0, row(parts[2])),
+                };
+                let sign = if op == "-=" { 1.0 } else { -1.0 };
+                for c in 0..m.columns {
+                    let value = m[source][c];
+                    m[target][c] -= sign * factor * value;
+                }
+            }
+        }
+    }
+
+    m
+}
+
+#[test]
+fn test_reduce_with_steps() {
+    let m = Matrix {
+        data: vec![3.0, 6.0, 1.0, 4.0],
+        rows: 2,
+        columns: 2,
+    };
+    let (reduced, steps) = m.reduce_with_steps();
+    assert_eq!(reduced, Matrix::identity(2));
+    assert_eq!(steps, vec!["R1 /= 3", "R2 -= R1", "R2 /= 2", "R1 -= 2*R2"]);
+    assert_eq!(replay_steps(&m, &steps), reduced);
+
+    // Partial pivoting swaps rows and negative factors are written as additions
+    let m = Matrix {
+        data: vec![1.0, 2.0, -1.0, -4.0, 1.0, 0.0],
+        rows: 2,
+        columns: 3,
+    };
+    let (reduced, steps) = m.reduce_with_steps();
+    assert_eq!(steps, vec!["R1 <-> R2", "R1 /= -4", "R2 -= R1", "R2 /= 2.25", "R1 += 0.25*R2"]);
+    let replayed = replay_steps(&m, &steps);
+    for (x, y) in replayed.data.iter().zip(reduced.data.iter()) {
+        assert_relative_eq!(x, y, epsilon = 1e-12);
+    }
+    assert_relative_eq!(reduced[0][0], 1.0);
+    assert_relative_eq!(reduced[1][1], 1.0);
+    assert_relative_eq!(reduced[0][1], 0.0);
+    assert_relative_eq!(reduced[1][0], 0.0);
+}
+
+#[test]
+fn test_powi() {
+    let m = Matrix {
+        data: vec![4.0, 7.0, 2.0, 6.0],
+        rows: 2,
+        columns: 2,
+    };
+    let inverse = m.inverse().unwrap();
+
+    assert_eq!(m.powi(0).unwrap(), Matrix::identity(2));
+    assert_eq!(m.powi(1).unwrap(), m);
+    assert_eq!(m.powi(2).unwrap(), m.multiply(&m).unwrap());
+    assert_eq!(m.powi(-1).unwrap(), inverse);
+
+    let expected = inverse.multiply(&inverse).unwrap();
+    let actual = m.powi(-2).unwrap();
+    for (x, y) in actual.data.iter().zip(expected.data.iter()) {
+        assert_relative_eq!(x, y, epsilon = 1e-12);
+    }
+
+    // A^3 * A^-3 = I
+    let product = m.powi(3).unwrap().multiply(&m.powi(-3).unwrap()).unwrap();
+    assert!(product.is_identity(1e-9));
+
+    let singular = Matrix {
+        data: vec![1.0, 2.0, 2.0, 4.0],
+        rows: 2,
+        columns: 2,
+    };
+    assert!(singular.powi(2).is_ok());
+    assert!(singular.powi(-1).is_err());
+    assert!(Matrix::ones(2, 3).powi(1).is_err());
+}
+
+#[test]
+fn test_invariants() {
+    let m = Matrix {
+        data: vec![2.0, -1.0, 3.0, 4.0, 0.5, 1.0, -2.0, 5.0, 1.5],
+        rows: 3,
+        columns: 3,
+    };
+    let invariants = m.invariants().unwrap();
+    assert_eq!(invariants.len(), 3);
+
+    // Sum of 2x2 principal minors
+    let a = |i: usize, j: usize| m[i][j];
+    let minor = |i: usize, j: usize| a(i, i) * a(j, j) - a(i, j) * a(j, i);
+    let second = minor(0, 1) + minor(0, 2) + minor(1, 2);
+
+    assert_relative_eq!(invariants[0], m.trace().unwrap(), epsilon = 1e-10);
+    assert_relative_eq!(invariants[1], second, epsilon = 1e-10);
+    assert_relative_eq!(invariants[2], m.determinant().unwrap(), epsilon = 1e-10);
+
+    // Preserved under similarity P A P^-1
+    let p = Matrix {
+        data: vec![1.0, 2.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+    let similar = p.multiply(&m).unwrap().multiply(&p.inverse().unwrap()).unwrap();
+    for (x, y) in similar.invariants().unwrap().iter().zip(invariants.iter()) {
+        assert_relative_eq!(x, y, epsilon = 1e-9);
+    }
+
+    assert!(Matrix::ones(2, 3).invariants().is_err());
+}
+
+#[test]
+fn test_masks() {
+    let v = Matrix { data: vec![-1.0, 0.0, 1.0], rows: 1, columns: 3 };
+    let mask = v.mask_gt(0.0);
+    assert_eq!(mask.data, vec![0.0, 0.0, 1.0]);
+    assert_eq!((mask.rows, mask.columns), (1, 3));
+
+    let other = Matrix { data: vec![10.0, 20.0, 30.0], rows: 1, columns: 3 };
+    let selected = v.select(&mask, &other).unwrap();
+    assert_eq!(selected.data, vec![10.0, 20.0, 1.0]);
+
+    assert!(v.select(&Matrix::ones(3, 1), &other).is_err());
+    assert!(v.select(&mask, &Matrix::ones(2, 2)).is_err());
+}
+
+#[test]
+fn test_iter_mut_indexed() {
+    let mut m = Matrix::ones(3, 3);
+    for (row, col, x) in m.iter_mut_indexed() {
+        if col > row {
+            *x = 0.0;
+        }
+    }
+    assert_eq!(m.data, vec![1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0]);
+
+    // Positions are reported correctly for rectangular matrices
+    let mut rect = Matrix::zeros(2, 3);
+    let positions: Vec<(usize, usize)> = rect.iter_mut_indexed().map(|(r, c, _)| (r, c)).collect();
+    assert_eq!(positions, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+}
+
+#[test]
+fn test_broadcast_subtraction() {
+    let m = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        rows: 2,
+        columns: 3,
+    };
+
+    // Subtracting each column's mean centers the columns
+    let column_means = Matrix { data: vec![2.5, 3.5, 4.5], rows: 1, columns: 3 };
+    let centered = m.subtract_row_vector(&column_means).unwrap();
+    assert_eq!(centered.data, vec![-1.5, -1.5, -1.5, 1.5, 1.5, 1.5]);
+
+    // Subtracting each row's mean centers the rows
+    let row_means = Matrix { data: vec![2.0, 5.0], rows: 2, columns: 1 };
+    let centered = m.subtract_column_vector(&row_means).unwrap();
+    assert_eq!(centered.data, vec![-1.0, 0.0, 1.0, -1.0, 0.0, 1.0]);
+
+    assert!(m.subtract_row_vector(&row_means).is_err());
+    assert!(m.subtract_column_vector(&column_means).is_err());
+    assert!(m.subtract_row_vector(&Matrix::zeros(3, 3)).is_err());
+}
+
+#[test]
+fn test_angle_degrees() {
+    let up = Matrix { data: vec![0.0, 1.0], rows: 2, columns: 1 };
+    assert_relative_eq!(up.angle_degrees().unwrap(), 90.0);
+
+    let down_left = Matrix { data: vec![-1.0, -1.0], rows: 1, columns: 2 };
+    assert_relative_eq!(down_left.angle_degrees().unwrap(), -135.0);
+    assert_relative_eq!(normalize_angle(down_left.angle_degrees().unwrap()), 225.0);
+
+    assert!(Matrix::zeros(3, 1).angle_degrees().is_err());
+
+    assert_eq!(normalize_angle(370.0), 10.0);
+    assert_eq!(normalize_angle(-370.0), 350.0);
+    assert_eq!(normalize_angle(360.0), 0.0);
+    assert!(normalize_angle(-1e-14) < 360.0);
+
+    assert_eq!(normalize_angle_signed(370.0), 10.0);
+    assert_eq!(normalize_angle_signed(180.0), -180.0);
+    assert_eq!(normalize_angle_signed(-190.0), 170.0);
+}
+
+#[test]
+fn test_diag_add() {
+    let m = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+        rows: 3,
+        columns: 3,
+    };
+    let shifted = m.diag_add(2.0).unwrap();
+    assert_eq!(shifted.data, vec![3.0, 2.0, 3.0, 4.0, 7.0, 6.0, 7.0, 8.0, 11.0]);
+    assert_eq!(shifted, (&m + &(&Matrix::identity(3) * 2.0)).unwrap());
+
+    assert!(Matrix::ones(2, 3).diag_add(1.0).is_err());
+}
+
+#[test]
+fn test_histogram() {
+    // 100 evenly spaced values in [0, 1)
+    let m = Matrix {
+        data: (0..100).map(|i| i as f64 / 100.0).collect(),
+        rows: 10,
+        columns: 10,
+    };
+    assert_eq!(m.histogram(4, 0.0, 1.0), vec![25, 25, 25, 25]);
+    assert_eq!(m.histogram(4, 0.0, 1.0).iter().sum::<usize>(), 100);
+
+    // Out-of-range and NaN values are ignored; max lands in the last bin
+    let m = Matrix {
+        data: vec![-1.0, 0.0, 0.5, 1.0, 1.5, f64::NAN],
+        rows: 2,
+        columns: 3,
+    };
+    assert_eq!(m.histogram(2, 0.0, 1.0), vec![1, 2]);
+
+    assert!(m.histogram(0, 0.0, 1.0).is_empty());
+    assert_eq!(m.histogram(3, 1.0, 1.0), vec![0, 0, 0]);
+}
+
+#[test]
+fn test_argmax() {
+    let m = Matrix {
+        data: vec![0.1, 0.7, 0.2,
+                   0.9, 0.05, 0.05,
+                   0.3, 0.3, 0.4,
+                   0.5, 0.5, 0.0],
+        rows: 4,
+        columns: 3,
+    };
+    // Last row has a tie; the first occurrence wins
+    assert_eq!(m.argmax_rows(), vec![1, 0, 2, 0]);
+    assert_eq!(m.argmax_columns(), vec![1, 0, 2]);
+
+    let with_nan = Matrix {
+        data: vec![f64::NAN, 1.0, 2.0, f64::NAN],
+        rows: 2,
+        columns: 2,
+    };
+    assert_eq!(with_nan.argmax_rows(), vec![1, 0]);
+}
+
+#[test]
+fn test_convolve_rows() {
+    let m = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0,
+                   2.0, 2.0, 6.0, 0.0],
+        rows: 2,
+        columns: 4,
+    };
+
+    let valid = m.convolve_rows(&[0.5, 0.5], Padding::Valid).unwrap();
+    assert_eq!((valid.rows, valid.columns), (2, 3));
+    assert_eq!(valid.data, vec![1.5, 2.5, 3.5, 2.0, 4.0, 3.0]);
+
+    // Same padding keeps the width; the first output sees an implicit zero
+    let same = m.convolve_rows(&[0.5, 0.5], Padding::Same).unwrap();
+    assert_eq!((same.rows, same.columns), (2, 4));
+    assert_eq!(same.data, vec![0.5, 1.5, 2.5, 3.5, 1.0, 2.0, 4.0, 3.0]);
+
+    // Asymmetric kernel is flipped: a first difference x[n] - x[n-1]
+    let diff = m.convolve_rows(&[1.0, -1.0], Padding::Valid).unwrap();
+    assert_eq!(diff.data, vec![1.0, 1.0, 1.0, 0.0, 4.0, -6.0]);
+
+    // Centered odd kernel in same mode
+    let smooth = m.convolve_rows(&[1.0, 1.0, 1.0], Padding::Same).unwrap();
+    assert_eq!(smooth.data, vec![3.0, 6.0, 9.0, 7.0, 4.0, 10.0, 8.0, 6.0]);
+
+    let too_long = m.convolve_rows(&[1.0; 5], Padding::Valid).unwrap();
+    assert_eq!((too_long.rows, too_long.columns), (2, 0));
+    assert!(m.convolve_rows(&[], Padding::Same).is_err());
+}
+
+#[test]
+fn test_verify_inverse() {
+    let m = Matrix {
+        data: vec![2.0, 0.0, 1.0,
+                   1.0, 3.0, 2.0,
+                   1.0, 1.0, 2.0],
+        rows: 3,
+        columns: 3,
+    };
+    let inv = m.inverse().unwrap();
+    assert!(m.verify_inverse(&inv, 1e-10));
+
+    let mut corrupted = inv.clone();
+    corrupted.data[4] += 1e-3;
+    assert!(!m.verify_inverse(&corrupted, 1e-10));
+    // A loose enough tolerance accepts the perturbation
+    assert!(m.verify_inverse(&corrupted, 1e-2));
+
+    // Incompatible shapes are never an inverse
+    assert!(!m.verify_inverse(&Matrix::identity(2), 1e-10));
+}
+
+fn assert_svd_reconstructs(m: &Matrix) {
+    let (u, s, vt) = m.svd().unwrap();
+    let k = m.rows.min(m.columns);
+    assert_eq!((u.rows, u.columns), (m.rows, k));
+    assert_eq!(s.len(), k);
+    assert_eq!((vt.rows, vt.columns), (k, m.columns));
+    assert!(s.windows(2).all(|w| w[0] >= w[1]));
+    assert!(s.iter().all(|&x| x >= 0.0));
+
+    let reconstructed = u
+        .multiply(&Matrix::diagonal(&s))
+        .unwrap()
+        .multiply(&vt)
+        .unwrap();
+    for (a, b) in reconstructed.data.iter().zip(m.data.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-8);
+    }
+
+    // Orthonormal factors
+    assert!(u.transpose().multiply(&u).unwrap().is_identity(1e-8));
+    assert!(vt.multiply(&vt.transpose()).unwrap().is_identity(1e-8));
+}
+
+#[test]
+fn test_svd() {
+    // Tall and wide matrices
+    let tall = Matrix {
+        data: vec![0.8, -1.3, 2.1,
+                   0.4, 0.9, -0.7,
+                   -1.6, 0.2, 1.1,
+                   2.5, 1.4, 0.3],
+        rows: 4,
+        columns: 3,
+    };
+    assert_svd_reconstructs(&tall);
+    assert_svd_reconstructs(&Matrix {
+        data: vec![1.0, 0.5, -2.0, 0.0, 3.1,
+                   -0.4, 2.2, 1.3, -1.7, 0.6,
+                   0.9, -0.8, 0.1, 2.4, -1.2],
+        rows: 3,
+        columns: 5,
+    });
+
+    // Known singular values: 3x2 with orthogonal columns of norm 5 and 1
+    let m = Matrix {
+        data: vec![3.0, 0.0,
+                   4.0, 0.0,
+                   0.0, 1.0],
+        rows: 3,
+        columns: 2,
+    };
+    let (_, s, _) = m.svd().unwrap();
+    assert_relative_eq!(s[0], 5.0, epsilon = 1e-12);
+    assert_relative_eq!(s[1], 1.0, epsilon = 1e-12);
+
+    // Singular values match the square roots of the eigenvalues of A^T A
+    let a = tall;
+    let (_, s, _) = a.svd().unwrap();
+    let eig = a.transpose().multiply(&a).unwrap().eigenvalues_symmetric().unwrap();
+    for (sigma, lambda) in s.iter().zip(eig.iter()) {
+        assert_relative_eq!(*sigma, lambda.max(0.0).sqrt(), epsilon = 1e-8);
+    }
+
+    assert!(Matrix::zeros(0, 0).svd().is_err());
+}
+
+#[test]
+fn test_pca() {
+    // Samples along the direction (1, 2, -1) with a tiny perpendicular wobble
+    let direction = [1.0, 2.0, -1.0];
+    let wobble = [2.0, -1.0, 0.0];
+    let mut data = Vec::new();
+    for (i, t) in [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0].iter().enumerate() {
+        let noise = if i % 2 == 0 { 1e-3 } else { -1e-3 };
+        for k in 0..3 {
+            data.push(5.0 + t * direction[k] + noise * wobble[k]);
+        }
+    }
+    let m = Matrix { data, rows: 6, columns: 3 };
+
+    let (projected, variances) = m.pca(3).unwrap();
+    assert_eq!((projected.rows, projected.columns), (6, 3));
+    assert!(variances.windows(2).all(|w| w[0] >= w[1]));
+    let total: f64 = variances.iter().sum();
+    assert!(variances[0] / total > 0.9999);
+
+    // Projection onto the first component recovers the spacing along the line
+    let (first, _) = m.pca(1).unwrap();
+    let step = direction.iter().map(|x| x * x).sum::<f64>().sqrt();
+    for i in 1..6 {
+        assert_relative_eq!((first.data[i] - first.data[i - 1]).abs(), step, epsilon = 1e-2);
+    }
+    // Projected data is centered
+    assert_relative_eq!(first.data.iter().sum::<f64>(), 0.0, epsilon = 1e-10);
+
+    assert!(m.pca(4).is_err());
+    assert!(Matrix::ones(1, 3).pca(1).is_err());
+}
+
+#[test]
+fn test_repeat_row_and_column() {
+    let row = Matrix { data: vec![1.0, 2.0], rows: 1, columns: 2 };
+    let stacked = Matrix::repeat_row(&row, 3).unwrap();
+    assert_eq!((stacked.rows, stacked.columns), (3, 2));
+    assert_eq!(stacked.data, vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+
+    let column = row.transpose();
+    let tiled = Matrix::repeat_column(&column, 3).unwrap();
+    assert_eq!((tiled.rows, tiled.columns), (2, 3));
+    assert_eq!(tiled.data, vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    assert_eq!(tiled, stacked.transpose());
+
+    // Shape is validated strictly
+    assert!(Matrix::repeat_row(&column, 3).is_err());
+    assert!(Matrix::repeat_column(&row, 3).is_err());
+    assert!(Matrix::repeat_row(&test_matrix(), 2).is_err());
+}
+
+#[test]
+fn test_clip_by_frobenius_norm() {
+    // ||m||_F = sqrt(36 + 64) = 10
+    let m = Matrix { data: vec![6.0, 0.0, 0.0, -8.0], rows: 2, columns: 2 };
+    let clipped = m.clip_by_frobenius_norm(5.0);
+    assert_relative_eq!(clipped.frobenius_inner(&clipped).unwrap().sqrt(), 5.0, epsilon = 1e-12);
+    assert_eq!(clipped.data, vec![3.0, 0.0, 0.0, -4.0]);
+
+    // Within the limit, or exactly at it: unchanged
+    assert_eq!(m.clip_by_frobenius_norm(10.0), m);
+    assert_eq!(m.clip_by_frobenius_norm(20.0), m);
+
+    let zeros = Matrix::zeros(2, 3);
+    assert_eq!(zeros.clip_by_frobenius_norm(1.0), zeros);
+    assert_eq!(zeros.clip_by_frobenius_norm(0.0), zeros);
+}
+
+#[test]
+fn test_normalize_or_zero() {
+    let v = Matrix { data: vec![3.0, 0.0, 4.0], rows: 1, columns: 3 };
+    let unit = v.normalize_or_zero().unwrap();
+    assert_relative_eq!(unit.magnitude().unwrap(), 1.0, epsilon = 1e-12);
+    assert_eq!((unit.rows, unit.columns), (1, 3));
+    assert_eq!(unit, v.unit_vector().unwrap());
+
+    let near_zero = Matrix { data: vec![1e-17, -1e-17, 0.0], rows: 3, columns: 1 };
+    assert_eq!(near_zero.normalize_or_zero().unwrap(), Matrix::zeros(3, 1));
+    assert_eq!(Matrix::zeros(3, 1).normalize_or_zero().unwrap(), Matrix::zeros(3, 1));
+
+    assert!(test_matrix().normalize_or_zero().is_err());
+}
+
+#[test]
+fn test_determinant_bareiss() {
+    // Wilson's matrix: determinant exactly 1, but ill-conditioned
+    let wilson = Matrix {
+        data: vec![10.0, 7.0, 8.0, 7.0,
+                   7.0, 5.0, 6.0, 5.0,
+                   8.0, 6.0, 10.0, 9.0,
+                   7.0, 5.0, 9.0, 10.0],
+        rows: 4,
+        columns: 4,
+    };
+    assert_eq!(wilson.determinant_bareiss().unwrap(), 1.0);
+    // The LU-based log-determinant agrees up to rounding
+    let (log_det, sign) = wilson.log_abs_determinant().unwrap();
+    assert_eq!(sign, 1);
+    assert_relative_eq!(log_det.exp(), 1.0, epsilon = 1e-10);
+
+    // Zero leading pivot forces a row swap
+    let swapped = Matrix {
+        data: vec![0.0, 2.0, 1.0, 3.0,
+                   1.0, 0.0, 4.0, 2.0,
+                   5.0, 1.0, 0.0, 1.0,
+                   2.0, 3.0, 1.0, 0.0],
+        rows: 4,
+        columns: 4,
+    };
+    assert_eq!(swapped.determinant_bareiss().unwrap(), swapped.determinant().unwrap());
+
+    // Singular: third row is the sum of the first two
+    let singular = Matrix {
+        data: vec![1.0, 2.0, 3.0,
+                   4.0, 5.0, 6.0,
+                   5.0, 7.0, 9.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_eq!(singular.determinant_bareiss().unwrap(), 0.0);
+
+    assert_eq!(test_matrix().determinant_bareiss().unwrap(), -2.0);
+    assert_eq!(Matrix::identity(1).determinant_bareiss().unwrap(), 1.0);
+    assert!(Matrix::zeros(2, 3).determinant_bareiss().is_err());
+}
+
+#[test]
+fn test_extract_band() {
+    let m = Matrix {
+        data: (1..=16).map(|x| x as f64).collect(),
+        rows: 4,
+        columns: 4,
+    };
+
+    let tridiagonal = m.extract_band(1, 1);
+    assert_eq!(tridiagonal.data, vec![1.0, 2.0, 0.0, 0.0,
+                                      5.0, 6.0, 7.0, 0.0,
+                                      0.0, 10.0, 11.0, 12.0,
+                                      0.0, 0.0, 15.0, 16.0]);
+
+    assert_eq!(m.extract_band(0, 0), Matrix::diagonal(&[1.0, 6.0, 11.0, 16.0]));
+    assert_eq!(m.extract_band(3, 3), m);
+    assert_eq!(m.extract_band(2, 0).data[1], 0.0);
+    assert_eq!(m.extract_band(2, 0).data[8], 9.0);
+
+    // Wide matrix: band follows the main diagonal
+    let wide = Matrix::ones(2, 4).extract_band(0, 1);
+    assert_eq!(wide.data, vec![1.0, 1.0, 0.0, 0.0,
+                               0.0, 1.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_spectral_moments() {
+    let m = Matrix {
+        data: vec![2.0, 1.0, 0.0,
+                   -1.0, 3.0, 4.0,
+                   0.5, 0.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+    let moments = m.spectral_moments(3).unwrap();
+    assert_eq!(moments.len(), 3);
+    assert_relative_eq!(moments[0], m.trace().unwrap());
+    assert_relative_eq!(moments[1], m.multiply(&m).unwrap().trace().unwrap());
+    // tr(A^2) = <A^T, A>_F
+    assert_relative_eq!(moments[1], m.transpose().frobenius_inner(&m).unwrap());
+    assert_relative_eq!(moments[2], m.powi(3).unwrap().trace().unwrap(), epsilon = 1e-12);
+
+    assert!(m.spectral_moments(0).unwrap().is_empty());
+    assert!(Matrix::zeros(2, 3).spectral_moments(2).is_err());
+}
+
+#[test]
+fn test_apply_conditional() {
+    let m = Matrix {
+        data: vec![-2.0, 0.0, 3.0,
+                   1.5, -100.0, -0.5],
+        rows: 2,
+        columns: 3,
+    };
+
+    let leaky = m.apply_conditional(|x| x >= 0.0, |x| x, |x| 0.01 * x);
+    assert_eq!((leaky.rows, leaky.columns), (2, 3));
+    let expected = [-0.02, 0.0, 3.0, 1.5, -1.0, -0.005];
+    for (a, b) in leaky.data.iter().zip(expected.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-15);
+    }
+
+    // Hard thresholding
+    let thresholded = m.apply_conditional(|x| x.abs() > 1.0, |x| x, |_| 0.0);
+    assert_eq!(thresholded.data, vec![-2.0, 0.0, 3.0, 1.5, -100.0, 0.0]);
+}
+
+#[test]
+fn test_normalize_rows() {
+    let m = Matrix {
+        data: vec![1.0, 2.0, 5.0,
+                   0.0, 0.0, 0.0,
+                   -3.0, 0.0, 4.0],
+        rows: 3,
+        columns: 3,
+    };
+
+    let l1 = m.normalize_rows_l1();
+    assert_relative_eq!(l1[0].iter().sum::<f64>(), 1.0, epsilon = 1e-15);
+    assert_relative_eq!(l1[2].iter().map(|x| x.abs()).sum::<f64>(), 1.0, epsilon = 1e-15);
+    assert_eq!(l1[1], [0.0, 0.0, 0.0]);
+    assert!(l1.data.iter().all(|x| !x.is_nan()));
+    assert_eq!(l1[2], [-3.0 / 7.0, 0.0, 4.0 / 7.0]);
+
+    let l2 = m.normalize_rows_l2();
+    for row in [0, 2] {
+        assert_relative_eq!(l2[row].iter().map(|x| x * x).sum::<f64>(), 1.0, epsilon = 1e-15);
+    }
+    assert_eq!(l2[1], [0.0, 0.0, 0.0]);
+    assert_eq!(l2[2], [-0.6, 0.0, 0.8]);
+}
+
+fn assert_diagonalization(m: &Matrix, epsilon: f64) {
+    let (p, d) = m.diagonalize().unwrap();
+    let n = m.rows;
+    assert!(d.extract_band(0, 0) == d);
+    assert!((1..n).all(|i| d.data[(i - 1) * n + i - 1] >= d.data[i * n + i]));
+
+    let reconstructed = p.multiply(&d).unwrap().multiply(&p.inverse().unwrap()).unwrap();
+    for (a, b) in reconstructed.data.iter().zip(m.data.iter()) {
+        assert_relative_eq!(a, b, epsilon = epsilon);
+    }
+}
+
+#[test]
+fn test_diagonalize() {
+    let symmetric = Matrix {
+        data: vec![4.0, 1.0, -2.0,
+                   1.0, 2.0, 0.5,
+                   -2.0, 0.5, 3.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_diagonalization(&symmetric, 1e-8);
+    let (p, _) = symmetric.diagonalize().unwrap();
+    assert!(p.transpose().multiply(&p).unwrap().is_identity(1e-10));
+
+    // Non-symmetric with distinct real eigenvalues 1, 4, 6
+    let triangular = Matrix {
+        data: vec![1.0, 2.0, 3.0,
+                   0.0, 4.0, 5.0,
+                   0.0, 0.0, 6.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_diagonalization(&triangular, 1e-8);
+    let (_, d) = triangular.diagonalize().unwrap();
+    assert_relative_eq!(d.data[0], 6.0, epsilon = 1e-10);
+    assert_relative_eq!(d.data[4], 4.0, epsilon = 1e-10);
+    assert_relative_eq!(d.data[8], 1.0, epsilon = 1e-10);
+
+    // S * diag(5, 2, 2) * S^-1: repeated but non-defective eigenvalue
+    let s = Matrix {
+        data: vec![1.0, 1.0, 0.0,
+                   0.0, 1.0, 1.0,
+                   1.0, 0.0, 2.0],
+        rows: 3,
+        columns: 3,
+    };
+    let repeated = s
+        .multiply(&Matrix::diagonal(&[5.0, 2.0, 2.0]))
+        .unwrap()
+        .multiply(&s.inverse().unwrap())
+        .unwrap();
+    assert_diagonalization(&repeated, 1e-8);
+
+    // Jordan block is defective, a rotation has complex eigenvalues
+    let jordan = Matrix { data: vec![2.0, 1.0, 0.0, 2.0], rows: 2, columns: 2 };
+    assert!(jordan.diagonalize().is_err());
+    let rotation = Matrix { data: vec![0.0, -1.0, 1.0, 0.0], rows: 2, columns: 2 };
+    assert!(rotation.diagonalize().is_err());
+    assert!(Matrix::zeros(2, 3).diagonalize().is_err());
+}
+
+#[test]
+fn test_matrix_exponential() {
+    let symmetric = Matrix {
+        data: vec![1.0, 0.5, 0.0,
+                   0.5, -2.0, 1.5,
+                   0.0, 1.5, 0.3],
+        rows: 3,
+        columns: 3,
+    };
+    let fast = symmetric.exp().unwrap();
+    let series = symmetric.exp_series().unwrap();
+    for (a, b) in fast.data.iter().zip(series.data.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-7);
+    }
+
+    // e^(-A) is the inverse of e^A
+    assert!(fast.verify_inverse(&symmetric.scalar_multiplication(-1.0).exp().unwrap(), 1e-10));
+
+    // Defective matrix falls back to the series: e^(tI + N) = e^t (I + N)
+    let jordan = Matrix { data: vec![2.0, 1.0, 0.0, 2.0], rows: 2, columns: 2 };
+    let e2 = 2.0_f64.exp();
+    let expected = [e2, e2, 0.0, e2];
+    for (a, b) in jordan.exp().unwrap().data.iter().zip(expected.iter()) {
+        assert_relative_eq!(a, b, max_relative = 1e-12);
+    }
+
+    // Complex eigenvalues: e^(θJ) is a rotation by θ
+    let theta = 0.7_f64;
+    let generator = Matrix { data: vec![0.0, -theta, theta, 0.0], rows: 2, columns: 2 };
+    let rotation = generator.exp().unwrap();
+    let expected = [theta.cos(), -theta.sin(), theta.sin(), theta.cos()];
+    for (a, b) in rotation.data.iter().zip(expected.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-12);
+    }
+
+    assert!(Matrix::zeros(2, 3).exp().is_err());
+
+    // Non-finite entries are rejected instead of driving the squaring loop forever
+    let infinite = Matrix { data: vec![f64::INFINITY, 0.0, 0.0, 1.0], rows: 2, columns: 2 };
+    assert!(infinite.exp_series().is_err());
+    assert!(infinite.exp().is_err());
+    let nan = Matrix { data: vec![0.0, f64::NAN, 1.0, 0.0], rows: 2, columns: 2 };
+    assert!(nan.exp_series().is_err());
+
+    // Finite entries near f64::MAX need more than 2^1023 of scaling
+    let overflowing = Matrix { data: vec![5e307, 1.0, 0.0, 5e307], rows: 2, columns: 2 };
+    assert!(overflowing.exp().is_err());
+    assert!(overflowing.exp_series().is_err());
+    assert!(Matrix::diagonal(&[800.0, 1.0]).exp().is_err());
+    // ‖A‖∞ overflows here, yet e^A underflows to zero
+    let decaying = Matrix { data: vec![-1e308, 1e308, 0.0, -1e308], rows: 2, columns: 2 };
+    assert!(decaying.exp_series().unwrap().is_zero(0.0));
+}
+
+#[test]
+fn test_clamp() {
+    let v = Matrix { data: vec![-5.0, 0.5, 5.0], rows: 1, columns: 3 };
+    let clamped = v.clamp(0.0, 1.0);
+    assert_eq!(clamped.data, vec![0.0, 0.5, 1.0]);
+    assert_eq!((clamped.rows, clamped.columns), (1, 3));
+
+    let m = Matrix { data: vec![-2.0, f64::NAN, 2.0, 0.0], rows: 2, columns: 2 };
+    let clamped = m.clamp(-1.0, 1.0);
+    assert_eq!(clamped.data[0], -1.0);
+    assert!(clamped.data[1].is_nan());
+    assert_eq!(&clamped.data[2..], &[1.0, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_clamp_inverted_range_panics() {
+    Matrix::ones(2, 2).clamp(1.0, 0.0);
+}
+
+#[test]
+fn test_inverse_and_determinant() {
+    let m = Matrix {
+        data: vec![0.0, 2.0, 1.0, 3.0,
+                   1.0, 0.0, 4.0, 2.0,
+                   5.0, 1.0, 0.0, 1.0,
+                   2.0, 3.0, 1.0, 0.0],
+        rows: 4,
+        columns: 4,
+    };
+    let (inverse, det) = m.inverse_and_determinant().unwrap();
+    assert_relative_eq!(det, -170.0, epsilon = 1e-10);
+    assert_relative_eq!(det, m.determinant().unwrap(), epsilon = 1e-10);
+    // Hand-computed entries of the inverse (first row is [-7, 2, 17, -1] / 85)
+    assert_relative_eq!(inverse[0][0], -7.0 / 85.0, epsilon = 1e-12);
+    assert_relative_eq!(inverse[0][2], 0.2, epsilon = 1e-12);
+    assert_relative_eq!(inverse[3][3], -41.0 / 170.0, epsilon = 1e-12);
+    assert!(m.verify_inverse(&inverse, 1e-12));
+
+    // Odd number of row swaps flips the sign
+    let swap = Matrix { data: vec![0.0, 1.0, 1.0, 0.0], rows: 2, columns: 2 };
+    assert_eq!(swap.inverse_and_determinant().unwrap(), (swap.clone(), -1.0));
+
+    let singular = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    assert!(singular.inverse_and_determinant().is_err());
+    assert!(Matrix::zeros(2, 3).inverse_and_determinant().is_err());
+}
+
+#[test]
+fn test_from_upper_triangle() {
+    let m = Matrix::from_upper_triangle(&[4.0, 1.0, -2.0, 2.0, 0.5, 3.0], 3).unwrap();
+    let expected = Matrix {
+        data: vec![4.0, 1.0, -2.0,
+                   1.0, 2.0, 0.5,
+                   -2.0, 0.5, 3.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_eq!(m, expected);
+    assert_eq!(m, m.transpose());
+
+    assert_eq!(Matrix::from_upper_triangle(&[], 0).unwrap().data.len(), 0);
+    assert!(Matrix::from_upper_triangle(&[1.0; 5], 3).is_err());
+    assert!(Matrix::from_upper_triangle(&[1.0; 9], 3).is_err());
+}
+
+#[test]
+fn test_nearest_orthogonal() {
+    let axis = Matrix { data: vec![1.0, 2.0, 2.0], rows: 3, columns: 1 };
+    let rotation = Quaternion::from_axis_angle(&axis, 0.8).unwrap().to_rotation_matrix();
+    assert!(rotation.is_orthogonal(1e-12));
+
+    // Simulate drift
+    let mut drifted = rotation.clone();
+    for (i, x) in drifted.data.iter_mut().enumerate() {
+        *x += 1e-4 * ((i as f64) * 1.7).sin();
+    }
+    assert!(!drifted.is_orthogonal(1e-6));
+
+    let fixed = drifted.nearest_orthogonal().unwrap();
+    assert!(fixed.is_orthogonal(1e-12));
+    for (a, b) in fixed.data.iter().zip(rotation.data.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-3);
+    }
+    // An orthogonal matrix is its own nearest orthogonal matrix
+    let same = rotation.nearest_orthogonal().unwrap();
+    for (a, b) in same.data.iter().zip(rotation.data.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-12);
+    }
+
+    let singular = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    assert!(singular.nearest_orthogonal().is_err());
+    assert!(Matrix::zeros(0, 0).nearest_orthogonal().is_err());
+    assert!(Matrix::ones(2, 3).nearest_orthogonal().is_err());
+}
+
+#[test]
+fn test_sample_grid() {
+    let m = Matrix::sample_grid((-1.0, 1.0), (0.0, 10.0), 3, 5, |x, y| x + y);
+    assert_eq!((m.rows, m.columns), (3, 5));
+    assert_eq!(m.get(0, 0), Some(-1.0));
+    assert_eq!(m.get(0, 4), Some(1.0));
+    assert_eq!(m.get(1, 2), Some(5.0));
+    assert_eq!(m.get(2, 1), Some(9.5));
+    assert_eq!(m.get(2, 4), Some(11.0));
+
+    // Non-commutative function confirms (x, y) order: columns vary x
+    let m = Matrix::sample_grid((0.0, 1.0), (0.0, 1.0), 2, 2, |x, y| x - 2.0 * y);
+    assert_eq!(m.data, vec![0.0, 1.0, -2.0, -1.0]);
+
+    let single = Matrix::sample_grid((3.0, 4.0), (5.0, 6.0), 1, 1, |x, y| x * y);
+    assert_eq!(single.data, vec![15.0]);
+}
+
+#[test]
+fn test_normalize_trace() {
+    let m = Matrix {
+        data: vec![2.0, 1.0, 0.0,
+                   1.0, 3.0, -1.0,
+                   0.0, -1.0, 5.0],
+        rows: 3,
+        columns: 3,
+    };
+    let rho = m.normalize_trace().unwrap();
+    assert_relative_eq!(rho.trace().unwrap(), 1.0, epsilon = 1e-15);
+    assert_relative_eq!(rho.data[1], 0.1);
+
+    let traceless = Matrix { data: vec![1.0, 2.0, 3.0, -1.0], rows: 2, columns: 2 };
+    assert!(traceless.normalize_trace().is_err());
+    assert!(Matrix::ones(2, 3).normalize_trace().is_err());
+}
+
+#[test]
+fn test_qr_column_pivoted() {
+    // Column 1 is twice column 0, so the rank is 2
+    let m = Matrix {
+        data: vec![1.0, 2.0, 0.0,
+                   1.0, 2.0, 1.0,
+                   0.0, 0.0, 3.0,
+                   0.0, 0.0, 1.0],
+        rows: 4,
+        columns: 3,
+    };
+    let (q, r, permutation) = m.qr_column_pivoted().unwrap();
+    assert_eq!((q.rows, q.columns), (4, 4));
+    assert_eq!((r.rows, r.columns), (4, 3));
+
+    // Largest column first, then the one with the larger residual; the dependent
+    // copy of it comes last
+    assert_eq!(permutation, vec![2, 1, 0]);
+
+    let diagonal: Vec<f64> = (0..3).map(|k| r.data[k * 3 + k].abs()).collect();
+    assert!(diagonal.windows(2).all(|w| w[0] >= w[1]));
+    let tol = 4.0 * f64::EPSILON * diagonal[0];
+    assert_eq!(diagonal.iter().filter(|&&d| d > tol).count(), 2);
+    assert_eq!(m.rank(), 2);
+
+    // A P = Q R with orthogonal Q and upper triangular R
+    assert!(q.is_orthogonal(1e-12));
+    assert!(r.data.iter().enumerate().all(|(i, &x)| i / 3 <= i % 3 || x == 0.0));
+    let qr = q.multiply(&r).unwrap();
+    for i in 0..4 {
+        for (j, &original) in permutation.iter().enumerate() {
+            assert_relative_eq!(qr[i][j], m[i][original], epsilon = 1e-12);
+        }
+    }
+
+    // Wide matrices keep every column in the permutation
+    let wide = Matrix {
+        data: vec![0.3, 1.8, -0.6, 1.1,
+                   2.4, -0.5, 0.9, 0.2],
+        rows: 2,
+        columns: 4,
+    };
+    let (q, r, permutation) = wide.qr_column_pivoted().unwrap();
+    let mut sorted = permutation.clone();
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 1, 2, 3]);
+    let qr = q.multiply(&r).unwrap();
+    for i in 0..2 {
+        for (j, &original) in permutation.iter().enumerate() {
+            assert_relative_eq!(qr[i][j], wide[i][original], epsilon = 1e-12);
+        }
+    }
+
+    assert!(Matrix::zeros(0, 0).qr_column_pivoted().is_err());
+}
+
+#[test]
+fn test_elementwise_functions() {
+    let squares = Matrix { data: vec![0.0, 1.0, 4.0, 2.25], rows: 2, columns: 2 };
+    assert_eq!(squares.sqrt().data, vec![0.0, 1.0, 2.0, 1.5]);
+
+    let signed = Matrix { data: vec![-1.0, 2.0, -3.0, 4.0], rows: 2, columns: 2 };
+    assert_eq!(signed.abs(), signed.clone().apply(f64::abs));
+    assert_eq!(signed.abs().data, vec![1.0, 2.0, 3.0, 4.0]);
+
+    let m = Matrix { data: vec![0.5, 1.0, 2.0, 3.0], rows: 2, columns: 2 };
+    let round_trip = m.exp_elementwise().ln();
+    for (a, b) in round_trip.data.iter().zip(m.data.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-15);
+    }
+    // Elementwise exp differs from the matrix exponential off the diagonal
+    assert_ne!(m.exp_elementwise(), m.exp().unwrap());
+
+    let fractional = Matrix { data: vec![-2.5, -0.2, 0.5, 1.7], rows: 1, columns: 4 };
+    assert_eq!(fractional.floor().data, vec![-3.0, -1.0, 0.0, 1.0]);
+    assert_eq!(fractional.ceil().data, vec![-2.0, -0.0, 1.0, 2.0]);
+    assert_eq!(fractional.round().data, vec![-3.0, -0.0, 1.0, 2.0]);
+    assert_eq!((fractional.round().rows, fractional.round().columns), (1, 4));
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let m = Matrix {
+        data: vec![1.0, -0.0, f64::MAX, f64::MIN_POSITIVE, 1.0 / 3.0, f64::NEG_INFINITY],
+        rows: 2,
+        columns: 3,
+    };
+    let bytes = m.to_bytes();
+    assert_eq!(bytes.len(), 16 + 6 * 8);
+
+    let restored = Matrix::from_bytes(&bytes).unwrap();
+    assert_eq!((restored.rows, restored.columns), (2, 3));
+    let bits = |m: &Matrix| m.data.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+    assert_eq!(bits(&restored), bits(&m));
+
+    // NaN survives bit-for-bit
+    let nan = Matrix { data: vec![f64::NAN], rows: 1, columns: 1 };
+    assert!(Matrix::from_bytes(&nan.to_bytes()).unwrap().data[0].is_nan());
+
+    let empty = Matrix::zeros(0, 5);
+    assert_eq!(Matrix::from_bytes(&empty.to_bytes()).unwrap(), empty);
+
+    // Truncated, padded, and oversized inputs
+    assert!(Matrix::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert!(Matrix::from_bytes(&[bytes.clone(), vec![0]].concat()).is_err());
+    assert!(Matrix::from_bytes(&bytes[..10]).is_err());
+    let mut huge = u64::MAX.to_le_bytes().to_vec();
+    huge.extend_from_slice(&2u64.to_le_bytes());
+    assert!(Matrix::from_bytes(&huge).is_err());
+}
+
+#[test]
+fn test_checked_hadamard_division() {
+    let a = Matrix { data: vec![2.0, 4.0, 6.0, 8.0], rows: 2, columns: 2 };
+    let b = Matrix { data: vec![1.0, 2.0, 0.0, 4.0], rows: 2, columns: 2 };
+
+    let err = a.checked_hadamard_division(&b).unwrap_err();
+    assert!(err.contains("(1, 0)"));
+    // The unchecked version still produces inf
+    assert!(a.hadamard_division(&b).unwrap().data[2].is_infinite());
+
+    let c = Matrix { data: vec![1.0, 2.0, -3.0, 4.0], rows: 2, columns: 2 };
+    assert_eq!(a.checked_hadamard_division(&c).unwrap().data, vec![2.0, 2.0, -2.0, 2.0]);
+    assert!(a.checked_hadamard_division(&Matrix::ones(2, 3)).is_err());
+}
+
+fn assert_lu_reconstructs(m: &Matrix) {
+    let (l, u, permutation) = m.lu_decomposition().unwrap();
+    let n = m.rows;
+
+    assert!((0..n).all(|i| l[i][i] == 1.0 && (i + 1..n).all(|j| l[i][j] == 0.0)));
+    assert!((0..n).all(|i| (0..i).all(|j| u[i][j] == 0.0)));
+    let mut sorted = permutation.clone();
+    sorted.sort();
+    assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+
+    // P^T * L * U == A, i.e. row i of L*U is row permutation[i] of A, relative to the
+    // size of the entries so that tiny-scale matrices are checked as strictly
+    let scale = m.data.iter().fold(0.0_f64, |max, &x| max.max(x.abs()));
+    let lu = l.multiply(&u).unwrap();
+    for (i, &original) in permutation.iter().enumerate() {
+        for j in 0..n {
+            assert_relative_eq!(lu[i][j], m[original][j], epsilon = 1e-12 * scale);
+        }
+    }
+}
+
+#[test]
+fn test_lu_decomposition() {
+    assert_lu_reconstructs(&Matrix {
+        data: vec![2.0, 1.0, 1.0,
+                   4.0, -6.0, 0.0,
+                   -2.0, 7.0, 2.0],
+        rows: 3,
+        columns: 3,
+    });
+    assert_lu_reconstructs(&Matrix {
+        data: vec![0.0, 2.0, 1.0, 3.0,
+                   1.0, 0.0, 4.0, 2.0,
+                   5.0, 1.0, 0.0, 1.0,
+                   2.0, 3.0, 1.0, 0.0],
+        rows: 4,
+        columns: 4,
+    });
+    assert_lu_reconstructs(&Matrix {
+        data: vec![0.3, -1.2, 2.5, 0.7,
+                   1.9, 0.4, -0.8, 2.2,
+                   -0.6, 2.7, 1.1, -1.5,
+                   0.9, -0.2, 3.1, 0.05],
+        rows: 4,
+        columns: 4,
+    });
+
+    // Tiny but invertible: pivots at or below f64::EPSILON are still eliminated
+    assert_lu_reconstructs(&Matrix {
+        data: vec![1e-20, 0.0, 1e-20, 1e-20],
+        rows: 2,
+        columns: 2,
+    });
+
+    // Singular: the zero pivot column is skipped instead of divided by
+    let singular = Matrix {
+        data: vec![1.0, 2.0, 3.0,
+                   2.0, 4.0, 7.0,
+                   3.0, 6.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_lu_reconstructs(&singular);
+    let (l, u, _) = singular.lu_decomposition().unwrap();
+    assert!(l.data.iter().chain(u.data.iter()).all(|x| x.is_finite()));
+    assert_eq!(u[1][1], 0.0);
+
+    assert_lu_reconstructs(&Matrix::zeros(3, 3));
+    assert!(Matrix::zeros(2, 3).lu_decomposition().is_err());
+}
+
+#[test]
+fn test_inverse_with_tol() {
+    // det = 16 * 2^-42 ≈ 3.6e-12 with entries of size 4
+    let nearly_singular = Matrix {
+        data: vec![4.0, 4.0, 4.0, 4.0 + 4.0 * 2f64.powi(-42)],
+        rows: 2,
+        columns: 2,
+    };
+    let (_, det) = nearly_singular.inverse_and_determinant().unwrap();
+    assert!(det.abs() < 1e-11);
+    assert!(nearly_singular.inverse().is_ok());
+    assert!(nearly_singular.inverse_with_tol(1e-10).is_err());
+
+    // The test is scale-invariant: a large, well-conditioned matrix passes
+    let large = Matrix {
+        data: vec![3e8, 1e8, 1e8, 2e8],
+        rows: 2,
+        columns: 2,
+    };
+    let inv = large.inverse_with_tol(1e-10).unwrap();
+    assert!(large.verify_inverse(&inv, 1e-12));
+    assert!(large.scalar_multiplication(1e-12).inverse_with_tol(1e-10).is_ok());
+
+    // A tiny but well-conditioned matrix passes even though every pivot is below f64::EPSILON
+    let tiny = Matrix {
+        data: vec![2e-20, 1e-20, 1e-20, 3e-20],
+        rows: 2,
+        columns: 2,
+    };
+    assert!(tiny.inverse().is_err());
+    let inv = tiny.inverse_with_tol(1e-10).unwrap();
+    assert!(tiny.verify_inverse(&inv, 1e-12));
+    assert_relative_eq!(inv.data[0], 0.6e20, max_relative = 1e-12);
+    let inv = Matrix::identity(3).scalar_multiplication(1e-20).inverse_with_tol(1e-10).unwrap();
+    for (x, y) in inv.data.iter().zip(Matrix::identity(3).scalar_multiplication(1e20).data.iter()) {
+        assert_relative_eq!(x, y, max_relative = 1e-12);
+    }
+
+    // 200x200 with ‖A‖∞^n ≈ 1e313 (beyond f64) but |det| / ‖A‖∞^n ≈ 1e-282
+    let n = 200;
+    let mut graded = Matrix::identity(n);
+    for i in 0..20 {
+        graded.data[i * n + i] = 36.6;
+    }
+    graded.data[n - 1] = 0.5;
+    let inv = graded.inverse_with_tol(1e-290).unwrap();
+    assert!(graded.verify_inverse(&inv, 1e-10));
+    assert!(graded.inverse_with_tol(1e-270).is_err());
+
+    // tol = 0 accepts every invertible matrix and still rejects singular ones
+    let mut shifted_ones = Matrix::ones(n, n);
+    for i in 0..n {
+        shifted_ones.data[i * n + i] += 1.0;
+    }
+    let inv = shifted_ones.inverse_with_tol(0.0).unwrap();
+    assert!(shifted_ones.verify_inverse(&inv, 1e-10));
+    let singular = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    assert!(singular.inverse_with_tol(0.0).is_err());
+
+    assert!(Matrix::zeros(2, 3).inverse_with_tol(1e-10).is_err());
+}
+
+#[test]
+fn test_pooling() {
+    let m = Matrix {
+        data: vec![1.0, 3.0, 2.0, 0.0,
+                   4.0, -1.0, 5.0, 7.0,
+                   0.0, 8.0, 1.0, 1.0,
+                   2.0, 2.0, 3.0, -6.0],
+        rows: 4,
+        columns: 4,
+    };
+
+    let max = m.max_pool(2, 2).unwrap();
+    assert_eq!((max.rows, max.columns), (2, 2));
+    assert_eq!(max.data, vec![4.0, 7.0, 8.0, 3.0]);
+
+    let avg = m.avg_pool(2, 2).unwrap();
+    assert_eq!(avg.data, vec![1.75, 3.5, 3.0, -0.25]);
+
+    // Overlapping windows: (4 - 3) / 1 + 1 = 2
+    let overlapping = m.max_pool(3, 1).unwrap();
+    assert_eq!(overlapping.data, vec![8.0, 8.0, 8.0, 8.0]);
+
+    // Incomplete trailing windows are dropped: (4 - 2) / 3 + 1 = 1
+    let strided = m.max_pool(2, 3).unwrap();
+    assert_eq!((strided.rows, strided.columns), (1, 1));
+    assert_eq!(strided.data, vec![4.0]);
+
+    let too_big = m.avg_pool(5, 1).unwrap();
+    assert_eq!((too_big.rows, too_big.columns), (0, 0));
+
+    assert!(m.max_pool(0, 1).is_err());
+    assert!(m.avg_pool(2, 0).is_err());
+}
+
+#[test]
+fn test_solve() {
+    let a = Matrix {
+        data: vec![0.0, 2.0, 1.0, 3.0,
+                   1.0, 0.0, 4.0, 2.0,
+                   5.0, 1.0, 0.0, 1.0,
+                   2.0, 3.0, 1.0, 0.0],
+        rows: 4,
+        columns: 4,
+    };
+
+    // Single right-hand side
+    let b = Matrix { data: vec![1.0, -2.0, 0.5, 3.0], rows: 4, columns: 1 };
+    let x = a.solve(&b).unwrap();
+    let expected = a.inverse().unwrap().multiply(&b).unwrap();
+    for (p, q) in x.data.iter().zip(expected.data.iter()) {
+        assert_relative_eq!(p, q, epsilon = 1e-10);
+    }
+
+    // Several right-hand sides at once
+    let many = Matrix {
+        data: vec![1.0, -2.0, 0.5,
+                   0.0, 3.0, 1.5,
+                   -1.0, 0.25, 2.0,
+                   4.0, 1.0, -0.75],
+        rows: 4,
+        columns: 3,
+    };
+    let x = a.solve(&many).unwrap();
+    assert_eq!((x.rows, x.columns), (4, 3));
+    let expected = a.inverse().unwrap().multiply(&many).unwrap();
+    for (p, q) in x.data.iter().zip(expected.data.iter()) {
+        assert_relative_eq!(p, q, epsilon = 1e-10);
+    }
+    let residual = a.multiply(&x).unwrap().subtraction(&many).unwrap();
+    assert!(residual.is_zero(1e-12));
+
+    // Solving against the identity gives the inverse
+    assert!(a.verify_inverse(&a.solve(&Matrix::identity(4)).unwrap(), 1e-12));
+
+    let singular = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    assert!(singular.solve(&Matrix::ones(2, 1)).is_err());
+    assert!(a.solve(&Matrix::ones(3, 1)).is_err());
+    assert!(Matrix::ones(2, 3).solve(&Matrix::ones(2, 1)).is_err());
+}
+
+#[test]
+fn test_correlate2d() {
+    // Vertical edge between columns 1 and 2
+    let image = Matrix {
+        data: vec![0.0, 0.0, 9.0, 9.0,
+                   0.0, 0.0, 9.0, 9.0,
+                   0.0, 0.0, 9.0, 9.0,
+                   0.0, 0.0, 9.0, 9.0],
+        rows: 4,
+        columns: 4,
+    };
+    // Sobel x: responds to left-to-right increases
+    let sobel_x = Matrix {
+        data: vec![-1.0, 0.0, 1.0,
+                   -2.0, 0.0, 2.0,
+                   -1.0, 0.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+
+    let valid = image.correlate2d(&sobel_x, 0).unwrap();
+    assert_eq!((valid.rows, valid.columns), (2, 2));
+    assert_eq!(valid.data, vec![36.0, 36.0, 36.0, 36.0]);
+
+    // Same-size output with zero padding; borders see the implicit zeros
+    let same = image.correlate2d(&sobel_x, 1).unwrap();
+    assert_eq!((same.rows, same.columns), (4, 4));
+    assert_eq!(same.data, vec![0.0, 27.0, 27.0, -27.0,
+                               0.0, 36.0, 36.0, -36.0,
+                               0.0, 36.0, 36.0, -36.0,
+                               0.0, 27.0, 27.0, -27.0]);
+
+    // Cross-correlation does not flip the kernel
+    let shift = Matrix { data: vec![0.0, 1.0], rows: 1, columns: 2 };
+    let row = Matrix { data: vec![1.0, 2.0, 3.0], rows: 1, columns: 3 };
+    assert_eq!(row.correlate2d(&shift, 0).unwrap().data, vec![2.0, 3.0]);
+
+    assert!(image.correlate2d(&Matrix::ones(5, 5), 0).is_err());
+    assert!(image.correlate2d(&Matrix::ones(5, 5), 1).is_ok());
+    assert!(image.correlate2d(&Matrix::zeros(0, 0), 0).is_err());
+}
+
+#[test]
+fn test_determinant_large() {
+    // Upper bidiagonal 10x10 with a row swap: det = -(1 * 2 * ... * 10)
+    let n = 10;
+    let mut m = Matrix::zeros(n, n);
+    for i in 0..n {
+        m.data[i * n + i] = (i + 1) as f64;
+        if i + 1 < n {
+            m.data[i * n + i + 1] = 1.0;
+        }
+    }
+    let mut swapped = m.clone();
+    swapped.data.swap(0, n);
+    swapped.data.swap(1, n + 1);
+    swapped.data.swap(2, n + 2);
+    assert_relative_eq!(m.determinant().unwrap(), 3628800.0, max_relative = 1e-12);
+    assert_relative_eq!(swapped.determinant().unwrap(), -3628800.0, max_relative = 1e-12);
+
+    // Agrees with the fraction-free Bareiss result on an integer matrix
+    let integer = Matrix {
+        data: (0..144).map(|k| ((k * k + 3 * k) % 13) as f64 - 6.0).collect(),
+        rows: 12,
+        columns: 12,
+    };
+    let exact = integer.determinant_bareiss().unwrap();
+    assert!(exact.abs() > 1e9);
+    assert_relative_eq!(integer.determinant().unwrap(), exact, max_relative = 1e-9);
+
+    // Singular matrices still give exactly zero
+    let dependent = Matrix {
+        data: (0..25).map(|k| (k % 5 + k / 5) as f64).collect(),
+        rows: 5,
+        columns: 5,
+    };
+    assert_eq!(dependent.determinant().unwrap(), 0.0);
+
+    // Badly scaled or tiny but nonsingular matrices are not mistaken for singular ones
+    let graded = Matrix::diagonal(&[1e10, 1.0, 1e-10]);
+    assert_relative_eq!(graded.determinant().unwrap(), 1.0, max_relative = 1e-12);
+    let tiny = Matrix::identity(3).scalar_multiplication(1e-17);
+    assert_relative_eq!(tiny.determinant().unwrap(), 1e-51, max_relative = 1e-12);
+    let sheared = Matrix {
+        data: vec![1.0, 1e16, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_eq!(sheared.determinant().unwrap(), 1.0);
+}
+
+#[test]
+fn test_inverse_gauss_jordan() {
+    // Diagonally dominant, hence invertible, 5x5
+    let a = Matrix {
+        data: vec![6.0, 1.0, -0.5, 2.0, 0.3,
+                   0.7, -5.5, 1.2, 0.0, 2.1,
+                   -1.0, 0.4, 7.0, 0.9, -1.5,
+                   0.2, 1.8, -0.6, 5.2, 0.8,
+                   1.1, 0.0, 2.3, -0.4, 6.4],
+        rows: 5,
+        columns: 5,
+    };
+    assert!(a.is_diagonally_dominant(true));
+    let inv = a.inverse().unwrap();
+    assert!(a.multiply(&inv).unwrap().is_identity(1e-10));
+    assert!(inv.multiply(&a).unwrap().is_identity(1e-10));
+
+    // Well-conditioned 6x6 with a zero leading entry forces pivoting
+    let b = Matrix {
+        data: vec![0.0, 2.0, 1.0, 0.0, -1.0, 1.0,
+                   3.0, 1.0, 0.0, 1.0, 0.0, -1.0,
+                   1.0, 0.0, 4.0, -1.0, 1.0, 0.0,
+                   0.0, 1.0, -1.0, 5.0, 0.0, 2.0,
+                   -1.0, 0.0, 1.0, 0.0, 3.0, 1.0,
+                   2.0, -1.0, 0.0, 1.0, 1.0, 4.0],
+        rows: 6,
+        columns: 6,
+    };
+    assert!(b.determinant().unwrap().abs() > 1.0);
+    let inv = b.inverse().unwrap();
+    assert!(b.multiply(&inv).unwrap().is_identity(1e-8));
+
+    // Pivot below EPSILON is still reported as singular
+    let mut singular = Matrix::identity(5);
+    singular.data[24] = 1e-17;
+    assert!(singular.inverse().is_err());
+}
+
+#[test]
+fn test_basis_vector() {
+    let e1 = Matrix::basis_vector(3, 1).unwrap();
+    assert_eq!((e1.rows, e1.columns), (3, 1));
+    assert_eq!(e1.data, vec![0.0, 1.0, 0.0]);
+
+    // Basis vectors are the columns of the identity
+    let columns: Vec<Matrix> = (0..4).map(|i| Matrix::basis_vector(4, i).unwrap()).collect();
+    assert_eq!(Matrix::from_column_vectors(&columns).unwrap(), Matrix::identity(4));
+
+    assert!(Matrix::basis_vector(3, 3).is_err());
+    assert!(Matrix::basis_vector(0, 0).is_err());
+}
+
+#[test]
+fn test_kth_diagonal() {
+    let m = Matrix {
+        data: (1..=9).map(|x| x as f64).collect(),
+        rows: 3,
+        columns: 3,
+    };
+    assert_eq!(m.kth_diagonal(1), vec![2.0, 6.0]);
+    assert_eq!(m.kth_diagonal(0), vec![1.0, 5.0, 9.0]);
+    assert_eq!(m.kth_diagonal(-2), vec![7.0]);
+    assert!(m.kth_diagonal(3).is_empty());
+    assert!(m.kth_diagonal(-3).is_empty());
+    assert!(m.kth_diagonal(i64::MIN).is_empty());
+
+    let wide = Matrix {
+        data: (1..=8).map(|x| x as f64).collect(),
+        rows: 2,
+        columns: 4,
+    };
+    assert_eq!(wide.kth_diagonal(0), vec![1.0, 6.0]);
+    assert_eq!(wide.kth_diagonal(2), vec![3.0, 8.0]);
+    assert_eq!(wide.kth_diagonal(3), vec![4.0]);
+}
+
+fn assert_qr_reconstructs(m: &Matrix) {
+    let (q, r) = m.qr_decomposition().unwrap();
+    assert_eq!((q.rows, q.columns), (m.rows, m.columns));
+    assert_eq!((r.rows, r.columns), (m.columns, m.columns));
+
+    assert!(q.transpose().multiply(&q).unwrap().is_identity(1e-10));
+    assert!(r.extract_band(0, m.columns) == r);
+    assert!(r.kth_diagonal(0).iter().all(|&d| d > 0.0));
+
+    let qr = q.multiply(&r).unwrap();
+    for (a, b) in qr.data.iter().zip(m.data.iter()) {
+        assert_relative_eq!(a, b, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn test_qr_decomposition() {
+    assert_qr_reconstructs(&Matrix {
+        data: vec![12.0, -51.0, 4.0,
+                   6.0, 167.0, -68.0,
+                   -4.0, 24.0, -41.0],
+        rows: 3,
+        columns: 3,
+    });
+    assert_qr_reconstructs(&Matrix {
+        data: vec![0.5, 1.2, -0.3, 2.0,
+                   1.7, -0.4, 0.8, 0.1,
+                   -1.1, 0.9, 2.2, -0.6,
+                   0.3, 2.5, -1.4, 1.0,
+                   2.1, 0.0, 0.7, -1.8,
+                   -0.2, 1.3, 0.4, 0.9],
+        rows: 6,
+        columns: 4,
+    });
+    assert_qr_reconstructs(&Matrix {
+        data: vec![2.0, 0.4, -0.7, 0.1, 0.9,
+                   0.3, 1.5, 0.6, -0.8, 0.2,
+                   -0.5, 0.8, 1.9, 0.4, -0.3,
+                   1.1, -0.2, 0.5, 1.7, 0.6,
+                   0.0, 0.9, -0.4, 0.3, 1.4],
+        rows: 5,
+        columns: 5,
+    });
+
+    // Classic example: R's first row is (14, 21, -14)
+    let (_, r) = Matrix {
+        data: vec![12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0],
+        rows: 3,
+        columns: 3,
+    }
+    .qr_decomposition()
+    .unwrap();
+    assert_relative_eq!(r[0][0], 14.0, epsilon = 1e-10);
+    assert_relative_eq!(r[0][1], 21.0, epsilon = 1e-10);
+    assert_relative_eq!(r[0][2], -14.0, epsilon = 1e-10);
+
+    // Orthogonal columns of very different length are independent
+    let (q, r) = Matrix::diagonal(&[1e20, 1.0]).qr_decomposition().unwrap();
+    assert_eq!(q, Matrix::identity(2));
+    assert_eq!(r.data, vec![1e20, 0.0, 0.0, 1.0]);
+    let (q, r) = Matrix { data: vec![1e16, 0.0, 0.0, 1.0, 0.0, 0.0], rows: 3, columns: 2 }
+        .qr_decomposition()
+        .unwrap();
+    assert_eq!(q.data, vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    assert_eq!(r.data, vec![1e16, 0.0, 0.0, 1.0]);
+
+    assert!(Matrix::ones(2, 3).qr_decomposition().is_err());
+    let dependent = Matrix { data: vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0], rows: 3, columns: 2 };
+    assert!(dependent.qr_decomposition().is_err());
+}
+
+#[test]
+fn test_take_row() {
+    let mut m = Matrix {
+        data: vec![1.0, 2.0,
+                   3.0, 4.0,
+                   5.0, 6.0],
+        rows: 3,
+        columns: 2,
+    };
+
+    assert_eq!(m.take_row(1).unwrap(), vec![3.0, 4.0]);
+    assert_eq!((m.rows, m.columns), (2, 2));
+    assert_eq!(m.data, vec![1.0, 2.0, 5.0, 6.0]);
+
+    assert!(m.take_row(2).is_err());
+    assert_eq!(m.take_row(1).unwrap(), vec![5.0, 6.0]);
+    // The last row stays
+    assert!(m.take_row(0).is_err());
+    assert_eq!((m.rows, m.data.clone()), (1, vec![1.0, 2.0]));
+}
+
+#[test]
+fn test_stochastic_predicates() {
+    let transition = Matrix {
+        data: vec![0.5, 0.25, 0.25,
+                   0.0, 1.0, 0.0,
+                   0.1, 0.6, 0.3],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(transition.is_row_stochastic(1e-12));
+    assert!(!transition.is_doubly_stochastic(1e-12));
+
+    let mut mis_summing = transition.clone();
+    mis_summing.data[8] = 0.35;
+    assert!(!mis_summing.is_row_stochastic(1e-12));
+    assert!(mis_summing.is_row_stochastic(0.1));
+
+    // Negative entries fail even when the rows sum to one
+    let negative = Matrix { data: vec![1.5, -0.5, 0.5, 0.5], rows: 2, columns: 2 };
+    assert!(!negative.is_row_stochastic(1.0));
+
+    let permutation = Matrix {
+        data: vec![0.0, 1.0, 0.0,
+                   0.0, 0.0, 1.0,
+                   1.0, 0.0, 0.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(permutation.is_doubly_stochastic(0.0));
+    assert!(Matrix::ones(3, 3).scalar_division(3.0).is_doubly_stochastic(1e-12));
+}
+
+#[test]
+fn test_rref() {
+    // Augmented system x + y + z = 6, 2x + y - z = 1, x - y + 2z = 5 with solution (1, 2, 3)
+    let system = Matrix {
+        data: vec![1.0, 1.0, 1.0, 6.0,
+                   2.0, 1.0, -1.0, 1.0,
+                   1.0, -1.0, 2.0, 5.0],
+        rows: 3,
+        columns: 4,
+    };
+    let expected = [1.0, 0.0, 0.0, 1.0,
+                    0.0, 1.0, 0.0, 2.0,
+                    0.0, 0.0, 1.0, 3.0];
+    let r = system.rref();
+    assert_eq!((r.rows, r.columns), (3, 4));
+    for (&actual, &wanted) in r.data.iter().zip(expected.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-12);
+    }
+
+    // Rank-deficient: the second pivot skips a column and the last row vanishes
+    let deficient = Matrix {
+        data: vec![1.0, 2.0, 0.0, 3.0,
+                   2.0, 4.0, 1.0, 7.0,
+                   3.0, 6.0, 1.0, 10.0],
+        rows: 3,
+        columns: 4,
+    };
+    let expected = [1.0, 2.0, 0.0, 3.0,
+                    0.0, 0.0, 1.0, 1.0,
+                    0.0, 0.0, 0.0, 0.0];
+    let r = deficient.rref();
+    for (&actual, &wanted) in r.data.iter().zip(expected.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-12);
+    }
+    assert_eq!(r[2], [0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(deficient.rank(), 2);
+
+    // Pivots may sit past the last row index
+    let wide = Matrix { data: vec![0.0, 0.0, 1.0], rows: 1, columns: 3 };
+    assert_eq!(wide.rank(), 1);
+}
+
+#[test]
+fn test_sinkhorn() {
+    let m = Matrix {
+        data: vec![1.0, 2.0, 3.0,
+                   4.0, 5.0, 6.0,
+                   7.0, 8.0, 0.5],
+        rows: 3,
+        columns: 3,
+    };
+    let p = m.sinkhorn(1000, 1e-10).unwrap();
+    assert!(p.is_doubly_stochastic(1e-10));
+
+    // Sinkhorn only rescales rows and columns: p = D1 * m * D2, so cross ratios survive
+    let ratio = |a: &Matrix| (a[0][0] * a[1][1]) / (a[0][1] * a[1][0]);
+    assert_relative_eq!(ratio(&p), ratio(&m), epsilon = 1e-9);
+
+    // Not enough rounds to converge
+    assert!(m.sinkhorn(1, 1e-12).is_err());
+
+    let with_zero = Matrix { data: vec![1.0, 0.0, 1.0, 1.0], rows: 2, columns: 2 };
+    assert!(with_zero.sinkhorn(100, 1e-10).is_err());
+    assert!(Matrix::ones(2, 3).sinkhorn(100, 1e-10).is_err());
+}
+
+#[test]
+fn test_flatten_unflatten() {
+    let m = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        rows: 2,
+        columns: 3,
+    };
+    let v = m.flatten();
+    assert_eq!((v.rows, v.columns), (6, 1));
+    assert_eq!(v.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    assert_eq!(Matrix::unflatten(&v, 2, 3).unwrap(), m);
+    assert_eq!(Matrix::unflatten(&v.transpose(), 2, 3).unwrap(), m);
+    assert_eq!(Matrix::unflatten(&v, 3, 2).unwrap().data, m.data);
+
+    assert!(Matrix::unflatten(&v, 2, 2).is_err());
+    assert!(Matrix::unflatten(&m, 2, 3).is_err());
+}
+
+#[test]
+fn test_congruence_transform() {
+    let a = Matrix {
+        data: vec![4.0, 1.0, -2.0,
+                   1.0, 3.0, 0.5,
+                   -2.0, 0.5, 5.0],
+        rows: 3,
+        columns: 3,
+    };
+    let p = Matrix {
+        data: vec![1.0, 2.0,
+                   -1.0, 0.5,
+                   3.0, 1.0],
+        rows: 3,
+        columns: 2,
+    };
+    let c = a.congruence_transform(&p).unwrap();
+    assert_eq!((c.rows, c.columns), (2, 2));
+    assert!(c.is_symmetric(1e-12));
+
+    let expected = p.transpose().multiply(&a).unwrap().multiply(&p).unwrap();
+    for (&actual, &wanted) in c.data.iter().zip(expected.data.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-12);
+    }
+
+    // An orthogonal change of basis keeps the trace
+    let (sin, cos) = 0.7_f64.sin_cos();
+    let q = Matrix {
+        data: vec![cos, -sin, 0.0,
+                   sin, cos, 0.0,
+                   0.0, 0.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert_relative_eq!(
+        a.congruence_transform(&q).unwrap().trace().unwrap(),
+        a.trace().unwrap(),
+        epsilon = 1e-12
+    );
+
+    assert!(a.congruence_transform(&Matrix::ones(2, 2)).is_err());
+    assert!(Matrix::ones(2, 3).congruence_transform(&Matrix::ones(2, 2)).is_err());
+}
+
+#[test]
+fn test_outer_product() {
+    let a = Matrix { data: vec![1.0, 2.0, 3.0], rows: 3, columns: 1 };
+    let b = Matrix { data: vec![4.0, 5.0], rows: 2, columns: 1 };
+    let outer = a.outer_product(&b).unwrap();
+    assert_eq!((outer.rows, outer.columns), (3, 2));
+    assert_eq!(outer.data, vec![4.0, 5.0, 8.0, 10.0, 12.0, 15.0]);
+
+    // Row vectors give the same result, and the product is a rank-one matrix
+    assert_eq!(a.transpose().outer_product(&b.transpose()).unwrap(), outer);
+    assert_eq!(outer.rank(), 1);
+    assert_eq!(b.outer_product(&a).unwrap(), outer.transpose());
+
+    assert!(a.outer_product(&Matrix::ones(2, 2)).is_err());
+    assert!(Matrix::ones(2, 2).outer_product(&b).is_err());
+}
+
+#[test]
+fn test_schur_complement() {
+    let m = Matrix {
+        data: vec![2.0, 0.0, 1.0, 2.0,
+                   0.0, 4.0, 3.0, 4.0,
+                   1.0, 0.0, 5.0, 6.0,
+                   0.0, 1.0, 7.0, 8.0],
+        rows: 4,
+        columns: 4,
+    };
+    // A^-1 = diag(0.5, 0.25), C = I, so C A^-1 B = [[0.5, 1], [0.75, 1]]
+    let s = m.schur_complement(2).unwrap();
+    assert_eq!((s.rows, s.columns), (2, 2));
+    assert_eq!(s.data, vec![4.5, 5.0, 6.25, 7.0]);
+
+    // det(M) = det(A) * det(M / A)
+    assert_relative_eq!(m.determinant().unwrap(), 8.0 * s.determinant().unwrap(), epsilon = 1e-12);
+
+    let singular_block = Matrix {
+        data: vec![1.0, 2.0, 0.0,
+                   2.0, 4.0, 1.0,
+                   0.0, 1.0, 1.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(singular_block.schur_complement(2).is_err());
+    assert!(m.schur_complement(0).is_err());
+    assert!(m.schur_complement(4).is_err());
+    assert!(Matrix::ones(2, 3).schur_complement(1).is_err());
+}
+
+#[test]
+fn test_nan_to_num() {
+    // Division by zero is a common source of non-finite values
+    let numerator = Matrix { data: vec![1.0, -1.0, 0.0, 2.0], rows: 2, columns: 2 };
+    let denominator = Matrix { data: vec![0.0, 0.0, 0.0, 4.0], rows: 2, columns: 2 };
+    let quotient = numerator.hadamard_division(&denominator).unwrap();
+    assert!(quotient.data[2].is_nan());
+
+    let cleaned = quotient.nan_to_num(-7.0, 100.0, -100.0);
+    assert_eq!(cleaned.data, vec![100.0, -100.0, -7.0, 0.5]);
+    assert_eq!((cleaned.rows, cleaned.columns), (2, 2));
+
+    // Finite values pass through untouched
+    let finite = test_matrix();
+    assert_eq!(finite.nan_to_num(0.0, 0.0, 0.0), finite);
+}
+
+#[test]
+fn test_submatrix() {
+    let m = Matrix {
+        data: (1..=16).map(f64::from).collect(),
+        rows: 4,
+        columns: 4,
+    };
+
+    let top_left = m.submatrix(0..2, 0..2).unwrap();
+    assert_eq!((top_left.rows, top_left.columns), (2, 2));
+    assert_eq!(top_left.data, vec![1.0, 2.0, 5.0, 6.0]);
+
+    assert_eq!(m.submatrix(0..4, 0..4).unwrap(), m);
+    assert_eq!(m.submatrix(1..4, 3..4).unwrap().data, vec![8.0, 12.0, 16.0]);
+
+    let empty = m.submatrix(2..2, 0..4).unwrap();
+    assert_eq!((empty.rows, empty.columns), (0, 4));
+
+    assert!(m.submatrix(0..5, 0..2).is_err());
+    assert!(m.submatrix(0..2, 3..5).is_err());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = m.submatrix(3..1, 0..2);
+    assert!(reversed.is_err());
+}
+
+#[test]
+fn test_all_finite() {
+    assert!(test_matrix().all_finite());
+    assert!(Matrix::zeros(0, 0).all_finite());
+
+    let blown_up = test_matrix().scalar_division(0.0);
+    assert!(!blown_up.all_finite());
+    assert!(blown_up.nan_to_num(0.0, 0.0, 0.0).all_finite());
+
+    let with_nan = Matrix { data: vec![1.0, f64::NAN, 3.0], rows: 3, columns: 1 };
+    assert!(!with_nan.all_finite());
+    let with_neg_inf = Matrix { data: vec![f64::NEG_INFINITY], rows: 1, columns: 1 };
+    assert!(!with_neg_inf.all_finite());
+}
+
+#[test]
+fn test_from_rows() {
+    let m = Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+    let flat = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        rows: 2,
+        columns: 3,
+    };
+    assert_eq!(m, flat);
+
+    let single = Matrix::from_rows(&[vec![7.0]]).unwrap();
+    assert_eq!((single.rows, single.columns), (1, 1));
+
+    assert!(Matrix::from_rows(&[]).is_err());
+    assert!(Matrix::from_rows(&[vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0]]).is_err());
+}
+
+#[test]
+fn test_outer_sum() {
+    let col = Matrix { data: vec![1.0, 2.0], rows: 2, columns: 1 };
+    let row = Matrix { data: vec![10.0, 20.0, 30.0], rows: 1, columns: 3 };
+    let grid = Matrix::outer_sum(&col, &row).unwrap();
+    assert_eq!((grid.rows, grid.columns), (2, 3));
+    assert_eq!(grid.data, vec![11.0, 21.0, 31.0, 12.0, 22.0, 32.0]);
+
+    // Squared distances ||x_i||^2 + ||y_j||^2 - 2 x_i . y_j between 1D points
+    let x = Matrix { data: vec![0.0, 3.0], rows: 2, columns: 1 };
+    let y = Matrix { data: vec![1.0, 5.0], rows: 1, columns: 2 };
+    let squared = |m: &Matrix| m.hadamard_multiplication(m).unwrap();
+    let cross = x.outer_product(&y).unwrap().scalar_multiplication(2.0);
+    let distances = Matrix::outer_sum(&squared(&x), &squared(&y)).unwrap().subtraction(&cross).unwrap();
+    assert_eq!(distances.data, vec![1.0, 25.0, 4.0, 4.0]);
+
+    assert!(Matrix::outer_sum(&row, &row).is_err());
+    assert!(Matrix::outer_sum(&col, &col).is_err());
+}
+
+#[test]
+fn test_partial_trace() {
+    // Product state ρ = σ ⊗ τ with tr(σ) = tr(τ) = 1 reduces to σ and τ
+    let sigma = Matrix { data: vec![0.75, 0.25, 0.25, 0.25], rows: 2, columns: 2 };
+    let tau = Matrix {
+        data: vec![0.5, 0.1, 0.0,
+                   0.1, 0.3, 0.05,
+                   0.0, 0.05, 0.2],
+        rows: 3,
+        columns: 3,
+    };
+    let mut rho = Matrix::zeros(6, 6);
+    for a in 0..2 {
+        for b in 0..3 {
+            for a2 in 0..2 {
+                for b2 in 0..3 {
+                    rho.data[(a * 3 + b) * 6 + a2 * 3 + b2] = sigma[a][a2] * tau[b][b2];
+                }
+            }
+        }
+    }
+
+    let reduced_a = rho.partial_trace(2, 3, 0).unwrap();
+    let reduced_b = rho.partial_trace(2, 3, 1).unwrap();
+    assert_eq!((reduced_a.rows, reduced_b.rows), (2, 3));
+    for (&actual, &wanted) in reduced_a.data.iter().zip(sigma.data.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-12);
+    }
+    for (&actual, &wanted) in reduced_b.data.iter().zip(tau.data.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-12);
+    }
+    assert_relative_eq!(reduced_a.trace().unwrap(), rho.trace().unwrap(), epsilon = 1e-12);
+
+    assert!(rho.partial_trace(2, 2, 0).is_err());
+    assert!(rho.partial_trace(2, 3, 2).is_err());
+    assert!(Matrix::ones(2, 3).partial_trace(1, 2, 0).is_err());
+}
+
+#[test]
+fn test_is_symmetric() {
+    let symmetric = Matrix {
+        data: vec![4.0, 1.0, -2.0,
+                   1.0, 3.0, 0.5,
+                   -2.0, 0.5, 5.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(symmetric.is_symmetric(0.0));
+    assert_eq!(symmetric.transpose(), symmetric);
+
+    let mut nearly = symmetric.clone();
+    nearly.data[2] += 1e-10;
+    assert!(nearly.is_symmetric(1e-9));
+    assert!(!nearly.is_symmetric(1e-11));
+
+    let asymmetric = Matrix {
+        data: vec![1.0, 2.0, 3.0,
+                   4.0, 5.0, 6.0,
+                   7.0, 8.0, 9.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(!asymmetric.is_symmetric(1.0));
+
+    // Non-square matrices are never symmetric, whatever the tolerance
+    assert!(!Matrix::ones(2, 3).is_symmetric(f64::INFINITY));
+}
+
+#[test]
+fn test_frobenius_norm() {
+    let m = test_matrix();
+    assert_eq!(m.frobenius_norm(), 30.0_f64.sqrt());
+
+    // Equals sqrt(tr(A^T A)) and the length of the flattened data
+    assert_relative_eq!(
+        m.frobenius_norm(),
+        m.transpose().multiply(&m).unwrap().trace().unwrap().sqrt(),
+        epsilon = 1e-12
+    );
+    let wide = Matrix { data: vec![3.0, 0.0, -4.0], rows: 1, columns: 3 };
+    assert_eq!(wide.frobenius_norm(), wide.magnitude().unwrap());
+
+    assert_eq!(Matrix::zeros(0, 0).frobenius_norm(), 0.0);
+}
+
+#[test]
+fn test_gershgorin_bounds() {
+    // Strictly diagonally dominant, so no disc contains zero and the matrix is invertible
+    let m = Matrix {
+        data: vec![10.0, 2.0, -3.0,
+                   1.0, -8.0, 2.0,
+                   0.5, 0.5, 4.0],
+        rows: 3,
+        columns: 3,
+    };
+    let discs = m.gershgorin_bounds().unwrap();
+    assert_eq!(discs, vec![(10.0, 5.0), (-8.0, 3.0), (4.0, 1.0)]);
+    assert!(discs.iter().all(|&(center, radius)| center.abs() > radius));
+    assert!(m.determinant().unwrap() != 0.0);
+
+    // Eigenvalues of a symmetric matrix fall inside the union of its discs
+    let s = Matrix {
+        data: vec![4.0, 1.0, -2.0,
+                   1.0, 3.0, 0.5,
+                   -2.0, 0.5, 5.0],
+        rows: 3,
+        columns: 3,
+    };
+    let discs = s.gershgorin_bounds().unwrap();
+    for lambda in s.eigenvalues_symmetric().unwrap() {
+        assert!(discs.iter().any(|&(center, radius)| (lambda - center).abs() <= radius + 1e-12));
+    }
+
+    assert!(Matrix::ones(2, 3).gershgorin_bounds().is_err());
+}
+
+#[test]
+fn test_induced_norms() {
+    let m = Matrix {
+        data: vec![1.0, -7.0, 2.0,
+                   -3.0, 4.0, -1.0,
+                   5.0, 0.5, -6.0],
+        rows: 3,
+        columns: 3,
+    };
+    // Column sums 9, 11.5, 9; row sums 10, 8, 11.5
+    assert_eq!(m.norm_1(), 11.5);
+    assert_eq!(m.norm_inf(), 11.5);
+    assert_eq!(m.transpose().norm_1(), m.norm_inf());
+
+    let asymmetric = Matrix { data: vec![1.0, -2.0, -3.0, 4.0], rows: 2, columns: 2 };
+    assert_eq!(asymmetric.norm_1(), 6.0);
+    assert_eq!(asymmetric.norm_inf(), 7.0);
+
+    // A single row or column: one norm sums everything, the other takes the max
+    let row = Matrix { data: vec![1.0, -2.0, 3.0], rows: 1, columns: 3 };
+    assert_eq!(row.norm_1(), 3.0);
+    assert_eq!(row.norm_inf(), 6.0);
+    let column = row.transpose();
+    assert_eq!(column.norm_1(), 6.0);
+    assert_eq!(column.norm_inf(), 3.0);
+
+    assert_eq!(Matrix::zeros(0, 3).norm_1(), 0.0);
+    assert_eq!(Matrix::zeros(3, 0).norm_inf(), 0.0);
+}
+
+#[test]
+fn test_is_diagonally_dominant() {
+    let dominant = Matrix {
+        data: vec![10.0, 2.0, -3.0,
+                   1.0, -8.0, 2.0,
+                   0.5, 0.5, 4.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(dominant.is_diagonally_dominant(true));
+    assert!(dominant.is_diagonally_dominant(false));
+
+    // Equality in the last row is only weakly dominant
+    let weak = Matrix {
+        data: vec![3.0, 1.0, 1.0,
+                   0.0, 2.0, 1.0,
+                   -1.0, 1.0, 2.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(weak.is_diagonally_dominant(false));
+    assert!(!weak.is_diagonally_dominant(true));
+
+    assert!(!test_matrix().is_diagonally_dominant(false));
+    assert!(!Matrix::ones(2, 3).is_diagonally_dominant(false));
+}
+
+#[test]
+fn test_solve_gauss_seidel() {
+    let a = Matrix {
+        data: vec![10.0, 2.0, -3.0,
+                   1.0, -8.0, 2.0,
+                   0.5, 0.5, 4.0],
+        rows: 3,
+        columns: 3,
+    };
+    assert!(a.is_diagonally_dominant(true));
+
+    let b = Matrix {
+        data: vec![1.0, -2.0,
+                   4.0, 0.0,
+                   -3.0, 5.0],
+        rows: 3,
+        columns: 2,
+    };
+    let x = a.solve_gauss_seidel(&b, 100, 1e-12).unwrap();
+    let direct = a.solve(&b).unwrap();
+    assert_eq!((x.rows, x.columns), (3, 2));
+    for (&iterative, &exact) in x.data.iter().zip(direct.data.iter()) {
+        assert_relative_eq!(iterative, exact, epsilon = 1e-10);
+    }
+
+    // Too few sweeps for the requested accuracy
+    assert!(a.solve_gauss_seidel(&b, 2, 1e-12).is_err());
+
+    let zero_diagonal = Matrix { data: vec![0.0, 1.0, 1.0, 0.0], rows: 2, columns: 2 };
+    assert!(zero_diagonal.solve_gauss_seidel(&Matrix::ones(2, 1), 10, 1e-6).is_err());
+    assert!(a.solve_gauss_seidel(&Matrix::ones(2, 1), 10, 1e-6).is_err());
+}
+
+#[test]
+fn test_adjugate() {
+    let a = Matrix {
+        data: vec![2.0, -1.0, 3.0,
+                   0.0, 4.0, 1.0,
+                   5.0, 2.0, -2.0],
+        rows: 3,
+        columns: 3,
+    };
+    let det = a.determinant().unwrap();
+    let expected = Matrix::identity(3).scalar_multiplication(det);
+    for product in [a.multiply(&a.adjugate().unwrap()).unwrap(), a.adjugate().unwrap().multiply(&a).unwrap()] {
+        for (&actual, &wanted) in product.data.iter().zip(expected.data.iter()) {
+            assert_relative_eq!(actual, wanted, epsilon = 1e-10);
+        }
+    }
+
+    // Cofactor expansion along the first row gives the determinant
+    let expansion: f64 = (0..3).map(|j| a[0][j] * a.cofactor(0, j).unwrap()).sum();
+    assert_relative_eq!(expansion, det, epsilon = 1e-10);
+    assert_relative_eq!(a.cofactor(1, 2).unwrap(), -9.0, epsilon = 1e-12);
+
+    // Still defined when the matrix is singular
+    let singular = Matrix { data: vec![1.0, 2.0, 2.0, 4.0], rows: 2, columns: 2 };
+    assert_eq!(singular.adjugate().unwrap().data, vec![4.0, -2.0, -2.0, 1.0]);
+    assert_eq!(Matrix::diagonal(&[5.0]).adjugate().unwrap().data, vec![1.0]);
+
+    assert!(a.cofactor(3, 0).is_err());
+    assert!(a.cofactor(0, 3).is_err());
+    assert!(Matrix::ones(2, 3).cofactor(0, 0).is_err());
+    assert!(Matrix::ones(2, 3).adjugate().is_err());
+}
+
+#[test]
+fn test_vector_slerp() {
+    let x = Matrix { data: vec![1.0, 0.0, 0.0], rows: 3, columns: 1 };
+    let y = Matrix { data: vec![0.0, 1.0, 0.0], rows: 3, columns: 1 };
+
+    // Halfway between orthogonal unit vectors bisects the right angle and stays unit length
+    let mid = x.slerp(&y, 0.5).unwrap();
+    let half = std::f64::consts::FRAC_1_SQRT_2;
+    assert_relative_eq!(mid.data[0], half, epsilon = 1e-12);
+    assert_relative_eq!(mid.data[1], half, epsilon = 1e-12);
+    assert_relative_eq!(mid.magnitude().unwrap(), 1.0, epsilon = 1e-12);
+
+    // Constant angular speed: a third of the way is 30 degrees from x
+    let third = x.slerp(&y, 1.0 / 3.0).unwrap();
+    assert_relative_eq!(third.dot_product(&x).unwrap(), 30.0_f64.to_radians().cos(), epsilon = 1e-12);
+
+    assert_eq!(x.slerp(&y, 0.0).unwrap(), x);
+    assert_relative_eq!(x.slerp(&y, 1.0).unwrap().data[1], 1.0, epsilon = 1e-12);
+
+    // Parallel and antiparallel inputs fall back to lerp
+    assert_eq!(x.slerp(&x, 0.3).unwrap(), x);
+    let opposite = -&x;
+    assert_eq!(x.slerp(&opposite, 0.25).unwrap().data, vec![0.5, 0.0, 0.0]);
+
+    assert!(x.slerp(&x.transpose(), 0.5).is_err());
+    assert!(x.slerp(&Matrix::ones(2, 1), 0.5).is_err());
+    assert!(Matrix::identity(3).slerp(&Matrix::identity(3), 0.5).is_err());
+}
+
+#[test]
+fn test_leading_principal_minors() {
+    // Tridiagonal [-1, 2, -1] is SPD with minors k + 1
+    let spd = Matrix {
+        data: vec![2.0, -1.0, 0.0,
+                   -1.0, 2.0, -1.0,
+                   0.0, -1.0, 2.0],
+        rows: 3,
+        columns: 3,
+    };
+    let minors = spd.leading_principal_minors().unwrap();
+    assert_eq!(minors.len(), 3);
+    for (&minor, expected) in minors.iter().zip([2.0, 3.0, 4.0]) {
+        assert_relative_eq!(minor, expected, epsilon = 1e-12);
+    }
+    assert!(minors.iter().all(|&m| m > 0.0));
+    assert!(spd.eigenvalues_symmetric().unwrap().iter().all(|&lambda| lambda > 0.0));
+
+    let indefinite = Matrix {
+        data: vec![1.0, 2.0, 0.0,
+                   2.0, 1.0, 0.0,
+                   0.0, 0.0, 3.0],
+        rows: 3,
+        columns: 3,
+    };
+    let minors = indefinite.leading_principal_minors().unwrap();
+    assert!(minors.iter().any(|&m| m <= 0.0));
+    assert_relative_eq!(minors[1], -3.0, epsilon = 1e-12);
+
+    assert!(Matrix::ones(2, 3).leading_principal_minors().is_err());
+}
+
+#[test]
+fn test_pseudo_inverse() {
+    let square = Matrix {
+        data: vec![2.0, -1.0, 3.0,
+                   0.0, 4.0, 1.0,
+                   5.0, 2.0, -2.0],
+        rows: 3,
+        columns: 3,
+    };
+    let pinv = square.pseudo_inverse().unwrap();
+    let inv = square.inverse().unwrap();
+    for (&actual, &wanted) in pinv.data.iter().zip(inv.data.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-10);
+    }
+
+    // Tall full-rank matrix: A+ is a left inverse
+    let tall = Matrix {
+        data: vec![1.0, 2.0,
+                   3.0, 4.0,
+                   5.0, 7.0],
+        rows: 3,
+        columns: 2,
+    };
+    let left = tall.pseudo_inverse().unwrap();
+    assert_eq!((left.rows, left.columns), (2, 3));
+    assert!(left.multiply(&tall).unwrap().is_identity(1e-10));
+
+    // Wide full-rank matrix: A+ is a right inverse
+    let wide = tall.transpose();
+    let right = wide.pseudo_inverse().unwrap();
+    assert_eq!((right.rows, right.columns), (3, 2));
+    assert!(wide.multiply(&right).unwrap().is_identity(1e-10));
+
+    let rank_deficient = Matrix {
+        data: vec![1.0, 2.0,
+                   2.0, 4.0,
+                   3.0, 6.0],
+        rows: 3,
+        columns: 2,
+    };
+    assert!(rank_deficient.pseudo_inverse().is_err());
+}
+
+#[test]
+fn test_kabsch() {
+    let points = Matrix {
+        data: vec![0.0, 0.0, 0.0,
+                   1.0, 0.0, 0.5,
+                   0.0, 2.0, -1.0,
+                   1.5, -1.0, 3.0,
+                   -2.0, 0.5, 1.0],
+        rows: 5,
+        columns: 3,
+    };
+    let axis = Matrix { data: vec![1.0, -2.0, 0.5], rows: 3, columns: 1 };
+    let rotation = Quaternion::from_axis_angle(&axis, 1.1).unwrap().to_rotation_matrix();
+    let shift = Matrix { data: vec![3.0, -1.0, 2.0], rows: 1, columns: 3 };
+    let moved = points
+        .multiply(&rotation.transpose())
+        .unwrap()
+        .subtract_row_vector(&-&shift)
+        .unwrap();
+
+    let recovered = points.kabsch(&moved).unwrap();
+    for (&actual, &wanted) in recovered.data.iter().zip(rotation.data.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-8);
+    }
+
+    // Coplanar points still determine a proper rotation
+    let planar = Matrix {
+        data: vec![0.0, 0.0, 0.0,
+                   1.0, 0.0, 0.0,
+                   0.0, 2.0, 0.0,
+                   1.0, 1.0, 0.0],
+        rows: 4,
+        columns: 3,
+    };
+    let recovered = planar.kabsch(&planar.multiply(&rotation.transpose()).unwrap()).unwrap();
+    for (&actual, &wanted) in recovered.data.iter().zip(rotation.data.iter()) {
+        assert_relative_eq!(actual, wanted, epsilon = 1e-8);
+    }
+
+    // A mirrored target yields the best proper rotation, not the reflection
+    let mirror = Matrix::diagonal(&[1.0, 1.0, -1.0]);
+    let best = points.kabsch(&points.multiply(&mirror).unwrap()).unwrap();
+    assert!(best.is_orthogonal(1e-10));
+    assert_relative_eq!(best.determinant().unwrap(), 1.0, epsilon = 1e-10);
+
+    assert!(points.kabsch(&planar).is_err());
+    assert!(Matrix::ones(3, 2).kabsch(&Matrix::ones(3, 2)).is_err());
+}
+
+#[test]
+fn test_reshape() {
+    let m = Matrix {
+        data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        rows: 2,
+        columns: 3,
+    };
+    let tall = m.reshape(3, 2).unwrap();
+    assert_eq!((tall.rows, tall.columns), (3, 2));
+    assert_eq!(tall.data, m.data);
+    assert_eq!(tall[2], [5.0, 6.0]);
+    // Not a transpose: the row-major order is kept
+    assert_ne!(tall, m.transpose());
+
+    assert_eq!(tall.reshape(2, 3).unwrap(), m);
+    assert_eq!(m.reshape(6, 1).unwrap(), m.flatten());
+
+    assert!(m.reshape(4, 2).is_err());
+    assert!(m.reshape(0, 6).is_err());
+}
+
+#[test]
+fn test_to_vector() {
+    let m = test_matrix();
+    let v = m.to_vector();
+    assert_eq!((v.rows, v.columns), (4, 1));
+    assert_eq!(v.data, vec![1.0, 2.0, 3.0, 4.0]);
+
+    // The vector view agrees with the matrix norms and inner products
+    assert_eq!(v.magnitude().unwrap(), 30.0_f64.sqrt());
+    assert_eq!(v.magnitude().unwrap(), m.frobenius_norm());
+    assert_eq!(v.dot_product(&v).unwrap(), m.frobenius_inner(&m).unwrap());
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "pgm")]
+
+use nelab::Matrix;
+
+fn render(m: &Matrix) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    m.to_pgm(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn test_pgm_header_and_pixels() {
+    let m = Matrix {
+        data: vec![-1.0, 0.0, 1.0,
+                   3.0, 2.0, -1.0],
+        rows: 2,
+        columns: 3,
+    };
+    let bytes = render(&m);
+
+    let header = b"P5\n3 2\n255\n";
+    assert_eq!(&bytes[..header.len()], header);
+    // Range [-1, 3]: -1 -> 0, 0 -> 64, 1 -> 128, 3 -> 255, 2 -> 191
+    assert_eq!(&bytes[header.len()..], &[0, 64, 128, 255, 191, 0]);
+}
+
+#[test]
+fn test_pgm_constant_and_nan() {
+    let constant = Matrix::ones(2, 2).scalar_multiplication(7.0);
+    assert_eq!(render(&constant), b"P5\n2 2\n255\n\x00\x00\x00\x00");
+
+    let with_nan = Matrix { data: vec![f64::NAN, 0.0, 10.0], rows: 3, columns: 1 };
+    assert_eq!(render(&with_nan), b"P5\n1 3\n255\n\x00\x00\xff");
+}
@@ -0,0 +1,117 @@
+use nelab::*;
+
+use approx::assert_relative_eq;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn vector(x: f64, y: f64, z: f64) -> Matrix {
+    Matrix {
+        data: vec![x, y, z],
+        rows: 3,
+        columns: 1,
+    }
+}
+
+fn assert_matrix_eq(a: &Matrix, b: &Matrix) {
+    assert_eq!((a.rows, a.columns), (b.rows, b.columns));
+    for (x, y) in a.data.iter().zip(b.data.iter()) {
+        assert_relative_eq!(x, y, epsilon = 1e-10);
+    }
+}
+
+#[test]
+fn test_rotate_vector() {
+    let q = Quaternion::from_axis_angle(&vector(0.0, 0.0, 1.0), FRAC_PI_2).unwrap();
+    let rotated = q.rotate_vector(&vector(1.0, 0.0, 0.0)).unwrap();
+    assert_matrix_eq(&rotated, &vector(0.0, 1.0, 0.0));
+
+    // Agrees with Rodrigues' rotation
+    let axis = vector(1.0, 2.0, -1.0);
+    let v = vector(0.5, -1.0, 2.0);
+    let q = Quaternion::from_axis_angle(&axis, 0.9).unwrap();
+    assert_matrix_eq(&q.rotate_vector(&v).unwrap(), &v.rotate_around_axis(&axis, 0.9).unwrap());
+
+    assert!(q.rotate_vector(&Matrix::zeros(2, 1)).is_err());
+    assert!(Quaternion::from_axis_angle(&vector(0.0, 0.0, 0.0), 1.0).is_err());
+}
+
+#[test]
+fn test_composition_matches_matrices() {
+    let q1 = Quaternion::from_axis_angle(&vector(0.0, 0.0, 1.0), FRAC_PI_2).unwrap();
+    let q2 = Quaternion::from_axis_angle(&vector(1.0, 1.0, 0.0), PI / 3.0).unwrap();
+    let v = vector(1.0, -2.0, 3.0);
+
+    // q2 * q1 applies q1 first, then q2
+    let composed = q2.multiply(&q1);
+    let via_quaternion = composed.rotate_vector(&v).unwrap();
+
+    let r = q2.to_rotation_matrix().multiply(&q1.to_rotation_matrix()).unwrap();
+    let via_matrix = r.multiply(&v).unwrap();
+    assert_matrix_eq(&via_quaternion, &via_matrix);
+    assert_matrix_eq(&composed.to_rotation_matrix(), &r);
+}
+
+#[test]
+fn test_rotation_matrix_round_trip() {
+    let axes = [
+        vector(1.0, 0.0, 0.0),
+        vector(0.0, 1.0, 0.0),
+        vector(0.3, -0.4, 0.8),
+    ];
+    for axis in &axes {
+        for &angle in &[0.2, 1.5, PI - 0.01, PI] {
+            let q = Quaternion::from_axis_angle(axis, angle).unwrap();
+            let m = q.to_rotation_matrix();
+            let back = Quaternion::from_rotation_matrix(&m).unwrap();
+            // q and -q are the same rotation
+            assert_matrix_eq(&back.to_rotation_matrix(), &m);
+            assert_relative_eq!(back.magnitude(), 1.0, epsilon = 1e-10);
+        }
+    }
+
+    assert!(Quaternion::from_rotation_matrix(&Matrix::identity(2)).is_err());
+}
+
+#[test]
+fn test_normalize_and_conjugate() {
+    let mut q = Quaternion { w: 1.0, x: 2.0, y: 2.0, z: 4.0 };
+    q.normalize().unwrap();
+    assert_relative_eq!(q.magnitude(), 1.0, epsilon = 1e-12);
+
+    // q * q* is the identity for unit quaternions
+    let product = q.multiply(&q.conjugate());
+    assert_relative_eq!(product.w, 1.0, epsilon = 1e-12);
+    assert_relative_eq!(product.x, 0.0, epsilon = 1e-12);
+
+    let mut zero = Quaternion { w: 0.0, x: 0.0, y: 0.0, z: 0.0 };
+    assert!(zero.normalize().is_err());
+}
+
+#[test]
+fn test_slerp() {
+    let axis = vector(0.0, 1.0, 0.0);
+    let start = Quaternion::from_axis_angle(&axis, 0.2).unwrap();
+    let end = Quaternion::from_axis_angle(&axis, 1.4).unwrap();
+
+    // Endpoints are reproduced
+    let at_start = start.slerp(&end, 0.0);
+    let at_end = start.slerp(&end, 1.0);
+    assert_matrix_eq(&at_start.to_rotation_matrix(), &start.to_rotation_matrix());
+    assert_matrix_eq(&at_end.to_rotation_matrix(), &end.to_rotation_matrix());
+
+    // Midpoint bisects the angle
+    let mid = start.slerp(&end, 0.5);
+    let expected = Quaternion::from_axis_angle(&axis, 0.8).unwrap();
+    assert_matrix_eq(&mid.to_rotation_matrix(), &expected.to_rotation_matrix());
+
+    // Shortest path: -end is the same rotation and gives the same midpoint
+    let flipped = Quaternion { w: -end.w, x: -end.x, y: -end.y, z: -end.z };
+    let mid_flipped = start.slerp(&flipped, 0.5);
+    assert_matrix_eq(&mid_flipped.to_rotation_matrix(), &expected.to_rotation_matrix());
+
+    // Nearly identical rotations use the linear fallback and stay normalized
+    let close = Quaternion::from_axis_angle(&axis, 0.2 + 1e-9).unwrap();
+    let blended = start.slerp(&close, 0.5);
+    assert_relative_eq!(blended.magnitude(), 1.0, epsilon = 1e-12);
+    let expected = Quaternion::from_axis_angle(&axis, 0.2 + 5e-10).unwrap();
+    assert_matrix_eq(&blended.to_rotation_matrix(), &expected.to_rotation_matrix());
+}